@@ -0,0 +1,110 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::contract_utils::Dict;
+
+const ATTESTATIONS_DICT: &str = "attestations";
+
+/// Most attestations kept per token; older ones are dropped as new ones are posted.
+pub const MAX_ATTESTATION_HISTORY: usize = 20;
+
+/// A custodian's statement that a cask was inspected in its warehouse.
+/// `volume` is in centilitres and `abv` in basis points.
+pub struct Attestation {
+    pub warehouse_id: String,
+    pub inspection_date: u64,
+    pub volume: u64,
+    pub abv: u32,
+    pub document_hash: String,
+    pub custodian: Key,
+    pub posted_at: u64,
+}
+
+impl CLTyped for Attestation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Attestation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.warehouse_id.to_bytes()?);
+        result.extend(self.inspection_date.to_bytes()?);
+        result.extend(self.volume.to_bytes()?);
+        result.extend(self.abv.to_bytes()?);
+        result.extend(self.document_hash.to_bytes()?);
+        result.extend(self.custodian.to_bytes()?);
+        result.extend(self.posted_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.warehouse_id.serialized_length()
+            + self.inspection_date.serialized_length()
+            + self.volume.serialized_length()
+            + self.abv.serialized_length()
+            + self.document_hash.serialized_length()
+            + self.custodian.serialized_length()
+            + self.posted_at.serialized_length()
+    }
+}
+
+impl FromBytes for Attestation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (warehouse_id, bytes) = String::from_bytes(bytes)?;
+        let (inspection_date, bytes) = u64::from_bytes(bytes)?;
+        let (volume, bytes) = u64::from_bytes(bytes)?;
+        let (abv, bytes) = u32::from_bytes(bytes)?;
+        let (document_hash, bytes) = String::from_bytes(bytes)?;
+        let (custodian, bytes) = Key::from_bytes(bytes)?;
+        let (posted_at, bytes) = u64::from_bytes(bytes)?;
+        Ok((
+            Attestation {
+                warehouse_id,
+                inspection_date,
+                volume,
+                abv,
+                document_hash,
+                custodian,
+                posted_at,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct Attestations {
+    dict: Dict,
+}
+
+impl Attestations {
+    pub fn instance() -> Attestations {
+        Attestations {
+            dict: Dict::instance(ATTESTATIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ATTESTATIONS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Vec<Attestation> {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn push(&self, token_id: &str, attestation: Attestation) {
+        let mut attestations = self.get(token_id);
+        if attestations.len() >= MAX_ATTESTATION_HISTORY {
+            attestations.remove(0);
+        }
+        attestations.push(attestation);
+        self.dict.set(token_id, attestations);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Vec<Attestation>>(token_id);
+    }
+}
@@ -12,29 +12,141 @@ use alloc::{
 use casper_contract::{
     contract_api::{
         runtime::{self, revert},
-        storage,
+        storage, system,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use cep47::{
-    contract_utils::{AdminControl, ContractContext, OnChainContractStorage},
+    contract_utils::{get_key, set_key, AdminControl, ContractContext, OnChainContractStorage},
     Error, Meta, TokenId, CEP47,
 };
 
 use casper_types::{
     contracts::NamedKeys, runtime_args, ApiError, CLType, CLTyped, CLValue, ContractPackageHash,
     EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs,
-    URef, U256,
+    URef, U256, U512,
 };
 
 mod minters_control;
 use minters_control::MinterControl;
 
+mod compliance_control;
+use compliance_control::ComplianceControl;
+
+mod custodian_control;
+use custodian_control::CustodianControl;
+
+mod attestations;
+use attestations::{Attestation, Attestations};
+
+mod oracle_control;
+use oracle_control::OracleControl;
+
+mod collections;
+use collections::{
+    Collection, CollectionSupplies, Collections, TokenCollections, DEFAULT_COLLECTION,
+};
+
 mod custom_data;
-use custom_data::Commissions;
+use custom_data::{
+    commission_account, derive_token_id, AllTokens, Allowances, BalancesOwed, CollateralLocks,
+    Commissions, Denylist, FeeIndexesPaid, FeesCarried, FeesPaidUntil, FillTimestamps,
+    Jurisdictions, PhysicalIds, SoulboundTokens, TokenChildren, TokenLineage, TokenParents,
+    TokenStatuses, TokenUsers,
+};
+
+mod documents;
+use documents::{is_blake2b_hex, TokenDocument, TokenDocuments};
+
+mod events;
+use events::{emit, CaskEvent};
+
+mod history;
+use history::{
+    OwnershipHistory, OwnershipRecord, OwnershipTimestamps, REASON_BURN, REASON_FORCE_TRANSFER,
+    REASON_LIQUIDATION, REASON_MINT, REASON_RECOVERY, REASON_SALE, REASON_TRANSFER,
+};
+
+mod insurance;
+use insurance::{Insurance, InsuranceRecord};
+
+mod quorum_control;
+use quorum_control::{next_arg, Proposal, QuorumControl};
+
+mod recovery;
+use recovery::{RecoveryRequest, RecoveryRequests};
+
+mod regauges;
+use regauges::{Regauge, Regauges};
+
+mod valuations;
+use valuations::{OwnerValuations, Valuation, Valuations};
+
+mod token_info;
+use token_info::TokenInfo;
 
 pub type Commission = BTreeMap<String, String>;
 pub const KYC_HASH: &str = "kyc_package_hash";
+pub const CONTRACT_PURSE: &str = "contract_purse";
+pub const DEFAULT_COMMISSION: &str = "default_commission";
+pub const COMMISSION_SOURCE_TOKEN: &str = "token";
+pub const COMMISSION_SOURCE_DEFAULT: &str = "default";
+pub const COMMISSION_SOURCE_COLLECTION: &str = "collection";
+pub const KYC_COUNTRY: &str = "country";
+pub const STATUS_ACTIVE: &str = "active";
+pub const STATUS_BOTTLED: &str = "bottled";
+pub const PARENT_ID: &str = "parent_id";
+pub const BOTTLE_NUMBER: &str = "bottle_number";
+pub const MERGE_POLICY_FIRST: &str = "FIRST";
+pub const MERGE_POLICY_UNION: &str = "UNION";
+pub const MERGE_POLICY_DEFAULT: &str = "DEFAULT";
+pub const TOKEN_ID_KEYS: &str = "token_id_keys";
+pub const UNIQUE_META_KEY: &str = "unique_meta_key";
+pub const ATTESTATION_MAX_AGE: &str = "attestation_max_age";
+pub const BLOCK_STALE_TRANSFERS: &str = "block_stale_transfers";
+pub const MIN_TRANSFER_AGE: &str = "min_transfer_age";
+pub const STORAGE_FEE: &str = "storage_fee";
+pub const STORAGE_FEE_GRACE_PERIOD: &str = "storage_fee_grace_period";
+pub const STORAGE_FEE_INDEX: &str = "storage_fee_index";
+pub const STORAGE_FEE_UPDATED_AT: &str = "storage_fee_updated_at";
+pub const BLOCK_UNPAID_TRANSFERS: &str = "block_unpaid_transfers";
+pub const TREASURY: &str = "treasury";
+pub const REQUIRE_INSURANCE: &str = "require_insurance";
+pub const RECOVERY_DELAY: &str = "recovery_delay";
+pub const RECOVERY_COUNT: &str = "recovery_count";
+pub const MAX_SUPPLY: &str = "max_supply";
+/// Entry points that can be put behind admin quorum approval, and actions such as
+/// `raise_supply_cap` that only a quorum can take.
+pub const GUARDABLE_ENTRY_POINTS: [&str; 20] = [
+    "transfer_from",
+    "force_transfer",
+    "request_recovery",
+    "execute_recovery",
+    "update_token_commission",
+    "set_default_commission",
+    "clear_token_commission",
+    "grant_admin",
+    "revoke_admin",
+    "grant_minter",
+    "grant_compliance",
+    "burn",
+    "merge_tokens",
+    "set_quorum_threshold",
+    "set_guarded_entry_point",
+    "set_recovery_delay",
+    "set_storage_fee",
+    "set_treasury",
+    "update_collection",
+    "raise_supply_cap",
+];
+/// Largest page returned by the enumeration entry points.
+pub const MAX_PAGE_SIZE: u32 = 100;
+/// Most bottles a single split can mint, keeping the deploy within the block gas limit.
+pub const MAX_BOTTLES_PER_SPLIT: u32 = 200;
+/// A Julian year, in milliseconds of block time.
+pub const YEAR: u64 = 31_557_600_000;
+/// Seven days, in milliseconds of block time.
+pub const DEFAULT_RECOVERY_DELAY: u64 = 7 * 24 * 60 * 60 * 1000;
 
 #[derive(Default)]
 struct CaskToken(OnChainContractStorage);
@@ -48,17 +160,172 @@ impl ContractContext<OnChainContractStorage> for CaskToken {
 impl CEP47<OnChainContractStorage> for CaskToken {}
 impl AdminControl<OnChainContractStorage> for CaskToken {}
 impl MinterControl<OnChainContractStorage> for CaskToken {}
+impl ComplianceControl<OnChainContractStorage> for CaskToken {}
+impl CustodianControl<OnChainContractStorage> for CaskToken {}
+impl OracleControl<OnChainContractStorage> for CaskToken {}
+impl QuorumControl<OnChainContractStorage> for CaskToken {}
 
 impl CaskToken {
     fn constructor(&mut self, name: String, symbol: String, meta: Meta) {
-        CEP47::init(self, name, symbol, meta);
+        CEP47::init(self, name.clone(), symbol, meta.clone());
         AdminControl::init(self);
         MinterControl::init(self);
+        ComplianceControl::init(self);
+        CustodianControl::init(self);
+        OracleControl::init(self);
+        QuorumControl::init(self);
         Commissions::init();
+        BalancesOwed::init();
+        runtime::put_key(CONTRACT_PURSE, system::create_purse().into());
+        set_key(DEFAULT_COMMISSION, Commission::new());
+        Jurisdictions::init();
+        Denylist::init();
+        OwnershipHistory::init();
+        OwnershipTimestamps::init();
+        RecoveryRequests::init();
+        AllTokens::init();
+        set_key(TOKEN_ID_KEYS, Vec::<String>::new());
+        set_key(UNIQUE_META_KEY, String::new());
+        PhysicalIds::init();
+        set_key(RECOVERY_DELAY, DEFAULT_RECOVERY_DELAY);
+        set_key(RECOVERY_COUNT, 0u64);
+        Attestations::init();
+        set_key(ATTESTATION_MAX_AGE, 0u64);
+        set_key(BLOCK_STALE_TRANSFERS, false);
+        TokenDocuments::init();
+        TokenStatuses::init();
+        TokenChildren::init();
+        TokenParents::init();
+        TokenLineage::init();
+        FillTimestamps::init();
+        set_key(MIN_TRANSFER_AGE, 0u64);
+        Regauges::init();
+        FeesPaidUntil::init();
+        FeeIndexesPaid::init();
+        FeesCarried::init();
+        set_key(STORAGE_FEE, U512::zero());
+        set_key(STORAGE_FEE_INDEX, U512::zero());
+        set_key(STORAGE_FEE_UPDATED_AT, u64::from(runtime::get_blocktime()));
+        set_key(STORAGE_FEE_GRACE_PERIOD, 0u64);
+        set_key(BLOCK_UNPAID_TRANSFERS, false);
+        set_key(TREASURY, Option::<Key>::None);
+        Insurance::init();
+        set_key(REQUIRE_INSURANCE, false);
+        Valuations::init();
+        OwnerValuations::init();
+        CollateralLocks::init();
+        TokenUsers::init();
+        SoulboundTokens::init();
+        Collections::init();
+        TokenCollections::init();
+        CollectionSupplies::init();
+        set_key(MAX_SUPPLY, Option::<U256>::None);
+        Collections::instance().set(
+            DEFAULT_COLLECTION,
+            Collection {
+                name,
+                meta,
+                commission: Commission::new(),
+                cap: None,
+                minters: Vec::new(),
+            },
+        );
+    }
+
+    fn token_commission(&self, token_id: TokenId) -> Option<(Commission, String)> {
+        self.owner_of(token_id.clone())?;
+        if let Some(commission) = Commissions::instance().get(&token_id) {
+            return Some((commission, COMMISSION_SOURCE_TOKEN.to_string()));
+        }
+        let collection_commission = self
+            .collection_of(token_id)
+            .and_then(|collection_id| Collections::instance().get(&collection_id))
+            .map(|collection| collection.commission)
+            .filter(|commission| !commission.is_empty());
+        match collection_commission {
+            Some(commission) => Some((commission, COMMISSION_SOURCE_COLLECTION.to_string())),
+            None => Some((
+                self.default_commission(),
+                COMMISSION_SOURCE_DEFAULT.to_string(),
+            )),
+        }
+    }
+
+    /// Reads at most `MAX_PAGE_SIZE` tokens, like the enumeration entry points.
+    fn token_metas(&self, token_ids: Vec<TokenId>) -> Vec<Option<Meta>> {
+        token_ids
+            .into_iter()
+            .take(MAX_PAGE_SIZE as usize)
+            .map(|token_id| self.token_meta(token_id))
+            .collect()
+    }
+
+    /// Reads at most `MAX_PAGE_SIZE` tokens, like the enumeration entry points.
+    fn token_commissions(&self, token_ids: Vec<TokenId>) -> Vec<Option<(Commission, String)>> {
+        token_ids
+            .into_iter()
+            .take(MAX_PAGE_SIZE as usize)
+            .map(|token_id| self.token_commission(token_id))
+            .collect()
+    }
+
+    fn token_info(&self, token_id: TokenId) -> Option<TokenInfo> {
+        let owner = self.owner_of(token_id.clone())?;
+        let (commission, commission_source) = self.token_commission(token_id.clone())?;
+        let (minted_at, last_transferred_at) = OwnershipTimestamps::instance()
+            .get(&token_id)
+            .unwrap_or_default();
+        Some(TokenInfo {
+            owner,
+            meta: self.token_meta(token_id.clone()).unwrap_or_default(),
+            commission,
+            commission_source,
+            status: self.token_status(&token_id),
+            minted_at,
+            last_transferred_at,
+            locked: self.is_token_locked(&token_id, owner),
+        })
+    }
+
+    /// Lifecycle stage of an existing token.
+    fn token_status(&self, token_id: &TokenId) -> String {
+        TokenStatuses::instance()
+            .get(token_id)
+            .unwrap_or_else(|| STATUS_ACTIVE.to_string())
+    }
+
+    /// Retired tokens, such as bottled casks, stay on-chain as a record but can't move.
+    fn is_token_retired(&self, token_id: &TokenId) -> bool {
+        TokenStatuses::instance().get(token_id).is_some()
     }
 
-    fn token_commission(&self, token_id: TokenId) -> Option<Commission> {
-        Commissions::instance().get(&token_id)
+    /// Whether the token currently cannot be moved by its owner.
+    fn is_token_locked(&self, token_id: &TokenId, owner: Key) -> bool {
+        self.is_denylisted(owner)
+            || self.is_soulbound(token_id)
+            || self.is_collateralized(token_id)
+            || self.is_token_retired(token_id)
+            || self.is_transfer_blocked_as_stale(token_id)
+            || self.is_storage_fee_overdue(token_id)
+            || self.is_blocked_as_uninsured(token_id)
+    }
+
+    fn default_commission(&self) -> Commission {
+        get_key(DEFAULT_COMMISSION).unwrap_or_default()
+    }
+
+    fn set_default_commission(&mut self, commission: Commission) {
+        self.assert_caller_is_admin();
+        set_key(DEFAULT_COMMISSION, commission);
+    }
+
+    fn clear_token_commission(&mut self, token_id: TokenId) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        Commissions::instance().remove(&token_id);
+        Ok(())
     }
 
     fn set_token_commission(
@@ -93,7 +360,12 @@ impl CaskToken {
                 let mut commission = commissions_dict.get(&token_id).unwrap_or_default();
                 commission.remove(&format!("{}_account", property));
                 commission.remove(&format!("{}_rate", property));
-                commissions_dict.set(&token_id, commission);
+                // An empty override would shadow the default commission.
+                if commission.is_empty() {
+                    commissions_dict.remove(&token_id);
+                } else {
+                    commissions_dict.set(&token_id, commission);
+                }
             }
             _ => {
                 return Err(Error::WrongArguments);
@@ -113,6 +385,232 @@ impl CaskToken {
         if !CaskToken::default().is_minter() && !CaskToken::default().is_admin(caller) {
             revert(ApiError::User(20));
         }
+        self.mint_tokens(
+            DEFAULT_COLLECTION,
+            recipient,
+            token_ids,
+            token_metas,
+            token_commissions,
+            true,
+        )
+    }
+
+    /// Mints tokens that stay with the recipient for good. Only a privileged burn
+    /// removes them. They go into the default collection.
+    fn mint_soulbound(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        token_commissions: Vec<Commission>,
+    ) -> Result<Vec<TokenId>, Error> {
+        let confirmed_token_ids =
+            self.mint(recipient, token_ids, token_metas, token_commissions)?;
+        let soulbound_tokens = SoulboundTokens::instance();
+        for token_id in &confirmed_token_ids {
+            soulbound_tokens.set(token_id);
+        }
+        Ok(confirmed_token_ids)
+    }
+
+    fn is_soulbound(&self, token_id: &TokenId) -> bool {
+        SoulboundTokens::instance().get(token_id)
+    }
+
+    fn create_collection(
+        &mut self,
+        collection_id: String,
+        name: String,
+        meta: Meta,
+        commission: Commission,
+        cap: Option<U256>,
+    ) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        let collections = Collections::instance();
+        if collection_id.is_empty() || collections.get(&collection_id).is_some() {
+            return Err(Error::WrongArguments);
+        }
+        collections.set(
+            &collection_id,
+            Collection {
+                name,
+                meta,
+                commission,
+                cap,
+                minters: Vec::new(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Renames a collection and replaces its metadata and commission, keeping its cap and
+    /// minters. A token's own commission still takes precedence over the collection's, and
+    /// an empty one falls back to `default_commission`, as the `default` collection's does
+    /// until it is updated.
+    fn update_collection(
+        &mut self,
+        collection_id: String,
+        name: String,
+        meta: Meta,
+        commission: Commission,
+    ) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        let collections = Collections::instance();
+        let mut collection = collections
+            .get(&collection_id)
+            .ok_or(Error::WrongArguments)?;
+        collection.name = name;
+        collection.meta = meta;
+        collection.commission = commission;
+        collections.set(&collection_id, collection);
+        Ok(())
+    }
+
+    fn grant_collection_minter(&mut self, collection_id: String, minter: Key) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        self.assert_not_denylisted(minter);
+        let collections = Collections::instance();
+        let mut collection = collections
+            .get(&collection_id)
+            .ok_or(Error::WrongArguments)?;
+        if !collection.minters.contains(&minter) {
+            collection.minters.push(minter);
+            collections.set(&collection_id, collection);
+        }
+        Ok(())
+    }
+
+    fn revoke_collection_minter(
+        &mut self,
+        collection_id: String,
+        minter: Key,
+    ) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        let collections = Collections::instance();
+        let mut collection = collections
+            .get(&collection_id)
+            .ok_or(Error::WrongArguments)?;
+        collection.minters.retain(|key| *key != minter);
+        collections.set(&collection_id, collection);
+        Ok(())
+    }
+
+    /// Mints into a collection, open to the contract-wide minters and the collection's own.
+    fn mint_to_collection(
+        &mut self,
+        collection_id: String,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        token_commissions: Vec<Commission>,
+    ) -> Result<Vec<TokenId>, Error> {
+        let collection = Collections::instance()
+            .get(&collection_id)
+            .ok_or(Error::WrongArguments)?;
+        let caller = self.get_caller();
+        if !self.is_minter() && !self.is_admin(caller) && !collection.minters.contains(&caller) {
+            revert(ApiError::User(20));
+        }
+        self.mint_tokens(
+            &collection_id,
+            recipient,
+            token_ids,
+            token_metas,
+            token_commissions,
+            true,
+        )
+    }
+
+    fn collection(&self, collection_id: String) -> Option<Collection> {
+        Collections::instance().get(&collection_id)
+    }
+
+    fn collection_of(&self, token_id: TokenId) -> Option<String> {
+        TokenCollections::instance().get(&token_id)
+    }
+
+    fn collection_supply(&self, collection_id: String) -> U256 {
+        CollectionSupplies::instance().get(&collection_id)
+    }
+
+    fn max_supply(&self) -> Option<U256> {
+        get_key(MAX_SUPPLY).unwrap_or_default()
+    }
+
+    /// Caps the global supply, or a collection's when `collection_id` is given. Once set,
+    /// a cap can only be lowered here; raising it takes a `raise_supply_cap` proposal.
+    fn set_supply_cap(&mut self, collection_id: Option<String>, cap: U256) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        self.update_supply_cap(collection_id, cap, false)
+    }
+
+    fn update_supply_cap(
+        &mut self,
+        collection_id: Option<String>,
+        cap: U256,
+        allow_raise: bool,
+    ) -> Result<(), Error> {
+        match collection_id {
+            None => {
+                if cap < self.total_supply() {
+                    return Err(Error::WrongArguments);
+                }
+                if let Some(max_supply) = self.max_supply() {
+                    if cap > max_supply && !allow_raise {
+                        return Err(Error::PermissionDenied);
+                    }
+                }
+                set_key(MAX_SUPPLY, Some(cap));
+            }
+            Some(collection_id) => {
+                let collections = Collections::instance();
+                let mut collection = collections
+                    .get(&collection_id)
+                    .ok_or(Error::WrongArguments)?;
+                if cap < CollectionSupplies::instance().get(&collection_id) {
+                    return Err(Error::WrongArguments);
+                }
+                if let Some(current) = collection.cap {
+                    if cap > current && !allow_raise {
+                        return Err(Error::PermissionDenied);
+                    }
+                }
+                collection.cap = Some(cap);
+                collections.set(&collection_id, collection);
+            }
+        }
+        Ok(())
+    }
+
+    /// Tokens that can still be minted globally, or into a collection when `collection_id`
+    /// is given, under whichever cap is tighter. `None` when nothing is capped.
+    fn remaining_supply(&self, collection_id: Option<String>) -> Option<U256> {
+        let global = self
+            .max_supply()
+            .map(|max_supply| max_supply.saturating_sub(self.total_supply()));
+        let collection = collection_id.and_then(|collection_id| {
+            let cap = Collections::instance().get(&collection_id)?.cap?;
+            Some(cap.saturating_sub(CollectionSupplies::instance().get(&collection_id)))
+        });
+        match (global, collection) {
+            (Some(global), Some(collection)) => Some(global.min(collection)),
+            (global, collection) => global.or(collection),
+        }
+    }
+
+    /// Mints into a collection without checking the caller, for entry points with their
+    /// own permissions. Only `casks` get ids derived from `token_id_keys` and accrue
+    /// storage fees; bottles split out of a cask do neither.
+    fn mint_tokens(
+        &mut self,
+        collection_id: &str,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        token_commissions: Vec<Commission>,
+        casks: bool,
+    ) -> Result<Vec<TokenId>, Error> {
+        self.assert_not_denylisted(recipient);
         let mut valid_token_commissions = token_commissions;
         match &token_ids {
             Some(token_ids) => {
@@ -129,19 +627,103 @@ impl CaskToken {
                 }
             }
         }
+        let collection = Collections::instance()
+            .get(collection_id)
+            .ok_or(Error::WrongArguments)?;
+        let collection_supplies = CollectionSupplies::instance();
+        let count = U256::from(token_metas.len());
+        if let Some(cap) = collection.cap {
+            if collection_supplies.get(collection_id) + count > cap {
+                return Err(Error::WrongArguments);
+            }
+        }
+        if let Some(max_supply) = self.max_supply() {
+            if self.total_supply() + count > max_supply {
+                return Err(Error::WrongArguments);
+            }
+        }
+        let token_ids = if casks {
+            self.derive_token_ids(token_ids, &token_metas)?
+        } else {
+            token_ids
+        };
+        let first_index = self.total_supply();
         let confirmed_token_ids =
-            CEP47::mint(self, recipient, token_ids, token_metas).unwrap_or_revert();
+            CEP47::mint(self, recipient, token_ids, token_metas.clone()).unwrap_or_revert();
+        for (token_id, token_meta) in confirmed_token_ids.iter().zip(token_metas.iter()) {
+            self.update_physical_id(token_id, None, Some(token_meta))?;
+        }
+        let all_tokens = AllTokens::instance();
+        for (offset, token_id) in confirmed_token_ids.iter().enumerate() {
+            all_tokens.add(first_index + offset, token_id);
+        }
+        self.record_ownership(&confirmed_token_ids, None, Some(recipient), REASON_MINT);
+        let token_collections = TokenCollections::instance();
+        for token_id in &confirmed_token_ids {
+            token_collections.set(token_id, collection_id);
+        }
+        collection_supplies.add(collection_id, count);
+        if casks {
+            let fees_paid_until = FeesPaidUntil::instance();
+            let fee_indexes_paid = FeeIndexesPaid::instance();
+            let now = u64::from(runtime::get_blocktime());
+            let fee_index = self.storage_fee_index();
+            for token_id in &confirmed_token_ids {
+                fees_paid_until.set(token_id, now);
+                fee_indexes_paid.set(token_id, fee_index);
+            }
+        }
         let commissions_dict = Commissions::instance();
         for (token_id, token_commission) in confirmed_token_ids
             .iter()
             .zip(valid_token_commissions.iter())
             .map(|(x, y)| (x, y))
         {
-            commissions_dict.set(token_id, token_commission.clone());
+            // Tokens minted without a commission fall back to the collection default.
+            if !token_commission.is_empty() {
+                commissions_dict.set(token_id, token_commission.clone());
+            }
         }
         Ok(confirmed_token_ids)
     }
 
+    /// Replaces the requested ids with ids derived from the metadata when the collection
+    /// is configured with `token_id_keys`. Explicit ids must match the derived ones.
+    fn derive_token_ids(
+        &self,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: &[Meta],
+    ) -> Result<Option<Vec<TokenId>>, Error> {
+        let keys = self.token_id_keys();
+        if keys.is_empty() {
+            return Ok(token_ids);
+        }
+        let mut derived_ids: Vec<TokenId> = Vec::new();
+        for token_meta in token_metas {
+            let token_id = derive_token_id(token_meta, &keys)?;
+            if derived_ids.contains(&token_id) {
+                return Err(Error::TokenIdAlreadyExists);
+            }
+            derived_ids.push(token_id);
+        }
+        if let Some(token_ids) = token_ids {
+            if token_ids != derived_ids {
+                return Err(Error::WrongArguments);
+            }
+        }
+        Ok(Some(derived_ids))
+    }
+
+    fn token_id_keys(&self) -> Vec<String> {
+        get_key(TOKEN_ID_KEYS).unwrap_or_default()
+    }
+
+    fn set_token_id_keys(&mut self, keys: Vec<String>) {
+        self.assert_caller_is_admin();
+        set_key(TOKEN_ID_KEYS, keys);
+    }
+
+    /// Mints into the default collection; `mint_to_collection` mints into the others.
     fn mint_copies(
         &mut self,
         recipient: Key,
@@ -164,26 +746,349 @@ impl CaskToken {
         self.mint(recipient, token_ids, token_metas, token_commissions)
     }
 
+    /// Retires a cask token and mints `count` bottle tokens to its owner, each linked to
+    /// the cask through `parent_id` and inheriting its commission.
+    fn split_into_bottles(
+        &mut self,
+        token_id: TokenId,
+        count: u32,
+        bottle_meta: Meta,
+    ) -> Result<Vec<TokenId>, Error> {
+        let caller = self.get_caller();
+        if !self.is_minter() && !self.is_admin(caller) {
+            revert(ApiError::User(20));
+        }
+        if count == 0 || count > MAX_BOTTLES_PER_SPLIT {
+            return Err(Error::WrongArguments);
+        }
+        let owner = self
+            .owner_of(token_id.clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        if self.is_token_retired(&token_id)
+            || self.is_collateralized(&token_id)
+            || self.is_soulbound(&token_id)
+        {
+            return Err(Error::WrongArguments);
+        }
+        let unique_key = self.unique_meta_key();
+        let token_metas = (1..=count)
+            .map(|bottle_number| {
+                let mut token_meta = bottle_meta.clone();
+                // The physical id stays registered to the cask, which outlives the split.
+                token_meta.remove(&unique_key);
+                token_meta.insert(PARENT_ID.to_string(), token_id.clone());
+                token_meta.insert(BOTTLE_NUMBER.to_string(), bottle_number.to_string());
+                token_meta
+            })
+            .collect();
+        let commission = Commissions::instance().get(&token_id).unwrap_or_default();
+        let collection_id = self.collection_of(token_id.clone()).unwrap_or_revert();
+        // Bottles aren't casks, so their ids aren't derived from the cask's keys and they
+        // don't accrue storage fees.
+        let children = self.mint_tokens(
+            &collection_id,
+            owner,
+            None,
+            token_metas,
+            vec![commission; count as usize],
+            false,
+        )?;
+
+        OwnerValuations::instance().sub(&owner, self.counted_valuation(&token_id));
+        // The cask leaves storage, so its fees stop accruing and stay owed as they stand.
+        FeesCarried::instance().set(&token_id, self.storage_fees_owed(token_id.clone()));
+        FeeIndexesPaid::instance().remove(&token_id);
+        TokenStatuses::instance().set(&token_id, STATUS_BOTTLED);
+        let parents = TokenParents::instance();
+        let fill_timestamps = FillTimestamps::instance();
+        let fill_timestamp = fill_timestamps.get(&token_id);
+        for child in &children {
+            parents.set(child, &token_id);
+            if let Some(fill_timestamp) = fill_timestamp {
+                fill_timestamps.set(child, fill_timestamp);
+            }
+        }
+        TokenChildren::instance().set(&token_id, children.clone());
+        Ok(children)
+    }
+
+    fn children_of(&self, token_id: TokenId) -> Vec<TokenId> {
+        TokenChildren::instance().get(&token_id)
+    }
+
+    fn parent_of(&self, token_id: TokenId) -> Option<TokenId> {
+        TokenParents::instance().get(&token_id)
+    }
+
+    /// Burns casks vatted together and mints the blend to their shared owner, with the
+    /// commission chosen by `commission_policy`: `FIRST` keeps the first input's own
+    /// commission, `UNION` combines the inputs' own commissions and `DEFAULT` leaves the
+    /// blend on the collection default.
+    fn merge_tokens(
+        &mut self,
+        token_ids: Vec<TokenId>,
+        new_meta: Meta,
+        commission_policy: String,
+    ) -> Result<TokenId, Error> {
+        let caller = self.get_caller();
+        if !self.is_admin(caller) && !self.is_custodian() {
+            revert(ApiError::User(20));
+        }
+        if token_ids.len() < 2 {
+            return Err(Error::WrongArguments);
+        }
+        let owner = self
+            .owner_of(token_ids[0].clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        for (index, token_id) in token_ids.iter().enumerate() {
+            if token_ids[..index].contains(token_id)
+                || self.is_token_retired(token_id)
+                || self.is_soulbound(token_id)
+                || self.is_collateralized(token_id)
+            {
+                return Err(Error::WrongArguments);
+            }
+            if self.owner_of(token_id.clone()) != Some(owner) {
+                return Err(Error::PermissionDenied);
+            }
+        }
+        let commission = self.merged_commission(&token_ids, &commission_policy)?;
+        // A blend is as old as its youngest cask, and of unknown age if any input is.
+        let fill_timestamp = token_ids
+            .iter()
+            .map(|token_id| self.fill_timestamp(token_id.clone()))
+            .collect::<Option<Vec<u64>>>()
+            .and_then(|fill_timestamps| fill_timestamps.into_iter().max());
+
+        // The blend owes what its casks still owe and is as overdue as the most overdue one.
+        let fees_owed = token_ids.iter().fold(U512::zero(), |total, token_id| {
+            total + self.storage_fees_owed(token_id.clone())
+        });
+        let fees_paid_until = FeesPaidUntil::instance();
+        let paid_until = token_ids
+            .iter()
+            .map(|token_id| fees_paid_until.get(token_id))
+            .min()
+            .unwrap_or_default();
+
+        let collection_id = self.collection_of(token_ids[0].clone()).unwrap_or_revert();
+
+        self.burn_tokens(owner, token_ids.clone(), None)?;
+        let token_id = self
+            .mint_tokens(
+                &collection_id,
+                owner,
+                None,
+                vec![new_meta],
+                vec![commission],
+                true,
+            )?
+            .pop()
+            .unwrap_or_revert();
+        TokenLineage::instance().set(&token_id, token_ids);
+        fees_paid_until.set(&token_id, paid_until);
+        if !fees_owed.is_zero() {
+            FeesCarried::instance().set(&token_id, fees_owed);
+        }
+        if let Some(fill_timestamp) = fill_timestamp {
+            FillTimestamps::instance().set(&token_id, fill_timestamp);
+        }
+        Ok(token_id)
+    }
+
+    fn merged_commission(
+        &self,
+        token_ids: &[TokenId],
+        commission_policy: &str,
+    ) -> Result<Commission, Error> {
+        let commissions_dict = Commissions::instance();
+        match commission_policy {
+            MERGE_POLICY_FIRST => Ok(commissions_dict.get(&token_ids[0]).unwrap_or_default()),
+            MERGE_POLICY_UNION => {
+                let mut merged = Commission::new();
+                for token_id in token_ids {
+                    for (key, value) in commissions_dict.get(token_id).unwrap_or_default() {
+                        // Inputs may share a recipient, but not disagree about one.
+                        if let Some(previous) = merged.insert(key, value.clone()) {
+                            if previous != value {
+                                return Err(Error::WrongArguments);
+                            }
+                        }
+                    }
+                }
+                Ok(merged)
+            }
+            MERGE_POLICY_DEFAULT => Ok(Commission::new()),
+            _ => Err(Error::WrongArguments),
+        }
+    }
+
+    fn lineage_of(&self, token_id: TokenId) -> Vec<TokenId> {
+        TokenLineage::instance().get(&token_id)
+    }
+
     fn burn(&mut self, owner: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
         let caller = CaskToken::default().get_caller();
         if !CaskToken::default().is_minter() && !CaskToken::default().is_admin(caller) {
             revert(ApiError::User(20));
         }
+        self.assert_storage_fees_settled(&token_ids)?;
+
+        self.burn_tokens(owner, token_ids, None)
+    }
+
+    fn owner_burn(
+        &mut self,
+        owner: Key,
+        token_ids: Vec<TokenId>,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        self.assert_not_denylisted(owner);
+        let caller = self.get_caller();
+        if caller != owner {
+            let allowances = Allowances::instance();
+            for token_id in &token_ids {
+                if allowances.get(&owner, token_id) != Some(caller) {
+                    return Err(Error::PermissionDenied);
+                }
+            }
+        }
+        if token_ids
+            .iter()
+            .any(|token_id| self.is_collateralized(token_id))
+        {
+            return Err(Error::PermissionDenied);
+        }
+        self.assert_storage_fees_settled(&token_ids)?;
+        self.burn_tokens(owner, token_ids, reason)
+    }
+
+    /// Burns the tokens and clears everything the contract keeps about them but their
+    /// ownership history, so a re-minted id starts afresh. Shared by the privileged and
+    /// the owner burn paths.
+    fn burn_tokens(
+        &mut self,
+        owner: Key,
+        token_ids: Vec<TokenId>,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        let mut last_index = self.total_supply();
+        let token_metas: Vec<Option<Meta>> = token_ids
+            .iter()
+            .map(|token_id| self.token_meta(token_id.clone()))
+            .collect();
+        CEP47::burn_internal(self, owner, token_ids.clone())?;
+        self.record_ownership(&token_ids, Some(owner), None, REASON_BURN);
+        for (token_id, token_meta) in token_ids.iter().zip(token_metas.iter()) {
+            self.update_physical_id(token_id, token_meta.as_ref(), None)?;
+        }
 
-        CEP47::burn_internal(self, owner, token_ids.clone()).unwrap_or_revert();
+        let all_tokens = AllTokens::instance();
+        for token_id in &token_ids {
+            last_index -= U256::one();
+            all_tokens.remove(token_id, last_index);
+        }
 
         let commissions_dict = Commissions::instance();
+        let allowances = Allowances::instance();
+        let jurisdictions_dict = Jurisdictions::instance();
+        let statuses = TokenStatuses::instance();
+        let fill_timestamps = FillTimestamps::instance();
+        let fees_paid_until = FeesPaidUntil::instance();
+        let fee_indexes_paid = FeeIndexesPaid::instance();
+        let fees_carried = FeesCarried::instance();
+        let insurance = Insurance::instance();
+        let valuations = Valuations::instance();
+        let owner_valuations = OwnerValuations::instance();
+        let collateral_locks = CollateralLocks::instance();
+        let token_users = TokenUsers::instance();
+        let soulbound_tokens = SoulboundTokens::instance();
+        let token_collections = TokenCollections::instance();
+        let collection_supplies = CollectionSupplies::instance();
+        let ownership_timestamps = OwnershipTimestamps::instance();
+        let attestations = Attestations::instance();
+        let documents = TokenDocuments::instance();
+        let regauges = Regauges::instance();
+        let parents = TokenParents::instance();
+        let children = TokenChildren::instance();
+        let lineage = TokenLineage::instance();
         for token_id in &token_ids {
+            owner_valuations.sub(&owner, self.counted_valuation(token_id));
             commissions_dict.remove(token_id);
+            allowances.remove(&owner, token_id);
+            jurisdictions_dict.remove(token_id);
+            statuses.remove(token_id);
+            fill_timestamps.remove(token_id);
+            fees_paid_until.remove(token_id);
+            fee_indexes_paid.remove(token_id);
+            fees_carried.remove(token_id);
+            insurance.remove(token_id);
+            valuations.remove(token_id);
+            collateral_locks.remove(token_id);
+            token_users.remove(token_id);
+            soulbound_tokens.remove(token_id);
+            if let Some(collection_id) = token_collections.get(token_id) {
+                collection_supplies.sub(&collection_id, U256::one());
+            }
+            token_collections.remove(token_id);
+            ownership_timestamps.remove(token_id);
+            attestations.remove(token_id);
+            documents.remove(token_id);
+            regauges.remove(token_id);
+            parents.remove(token_id);
+            children.remove(token_id);
+            lineage.remove(token_id);
+        }
+        if let Some(reason) = reason {
+            emit(&CaskEvent::Burn {
+                owner,
+                token_ids,
+                reason,
+            });
         }
         Ok(())
     }
 
+    fn tokens_of(&self, owner: Key, offset: U256, limit: u32) -> Vec<TokenId> {
+        let mut token_ids = Vec::new();
+        for index in 0..limit.min(MAX_PAGE_SIZE) {
+            let token_id = offset
+                .checked_add(U256::from(index))
+                .and_then(|index| self.get_token_by_index(owner, index));
+            match token_id {
+                Some(token_id) => token_ids.push(token_id),
+                None => break,
+            }
+        }
+        token_ids
+    }
+
+    fn token_by_index(&self, index: U256) -> Option<TokenId> {
+        AllTokens::instance().get(index)
+    }
+
+    fn all_tokens(&self, offset: U256, limit: u32) -> Vec<TokenId> {
+        let all_tokens = AllTokens::instance();
+        let mut token_ids = Vec::new();
+        for index in 0..limit.min(MAX_PAGE_SIZE) {
+            let token_id = offset
+                .checked_add(U256::from(index))
+                .and_then(|index| all_tokens.get(index));
+            match token_id {
+                Some(token_id) => token_ids.push(token_id),
+                None => break,
+            }
+        }
+        token_ids
+    }
+
     fn set_token_meta(&mut self, token_id: TokenId, token_meta: Meta) -> Result<(), Error> {
         let caller = CaskToken::default().get_caller();
         if !CaskToken::default().is_minter() && !CaskToken::default().is_admin(caller) {
             revert(ApiError::User(20));
         }
+        let old_meta = self.token_meta(token_id.clone());
+        self.update_physical_id(&token_id, old_meta.as_ref(), Some(&token_meta))?;
         CEP47::set_token_meta(self, token_id, token_meta).unwrap_or_revert();
         Ok(())
     }
@@ -198,429 +1103,3267 @@ impl CaskToken {
         if !CaskToken::default().is_minter() && !CaskToken::default().is_admin(caller) {
             revert(ApiError::User(20));
         }
-        let mut token_meta = CaskToken::default()
+        let old_meta = CaskToken::default()
             .token_meta(token_id.clone())
             .unwrap_or_revert();
+        let mut token_meta = old_meta.clone();
         token_meta.insert(token_meta_key, token_meta_value);
+        self.update_physical_id(&token_id, Some(&old_meta), Some(&token_meta))?;
         CEP47::set_token_meta(self, token_id, token_meta).unwrap_or_revert();
         Ok(())
     }
 
-    fn update_token_commission(
-        &mut self,
-        token_id: TokenId,
-        property: String,
-        mode: String,
-        account: Key,
-        value: String,
-    ) -> Result<(), Error> {
-        let caller = CaskToken::default().get_caller();
-        if !CaskToken::default().is_admin(caller) {
-            revert(ApiError::User(20));
+    fn unique_meta_key(&self) -> String {
+        get_key(UNIQUE_META_KEY).unwrap_or_default()
+    }
+
+    /// The registry can't be rebuilt on-chain, so the key is fixed once tokens exist.
+    fn set_unique_meta_key(&mut self, key: String) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        if !self.total_supply().is_zero() {
+            return Err(Error::WrongArguments);
         }
-        self.set_token_commission(token_id, property, mode, account, value)
-            .unwrap_or_revert();
+        set_key(UNIQUE_META_KEY, key);
         Ok(())
     }
 
-    fn get_kyc_hash(&self) -> ContractPackageHash {
-        let uref = runtime::get_key(KYC_HASH)
-            .unwrap_or_revert_with(ApiError::User(100))
-            .into_uref()
-            .unwrap_or_revert_with(ApiError::User(101));
-
-        storage::read(uref)
-            .unwrap_or_revert_with(ApiError::User(102))
-            .unwrap_or_revert_with(ApiError::User(103))
+    fn token_by_physical_id(&self, physical_id: String) -> Option<TokenId> {
+        PhysicalIds::instance().get(&physical_id)
     }
 
-    fn is_kyc_proved(&self, account: Key) -> bool {
-        runtime::get_caller();
-        runtime::call_versioned_contract::<bool>(
-            self.get_kyc_hash(),
-            None,
-            "is_kyc_proved",
-            runtime_args! {"account" => account, "index" => Option::<U256>::None},
-        );
-        true
+    /// Moves the token's physical id registration from `old_meta` to `new_meta`,
+    /// rejecting ids already registered to a token.
+    fn update_physical_id(
+        &self,
+        token_id: &TokenId,
+        old_meta: Option<&Meta>,
+        new_meta: Option<&Meta>,
+    ) -> Result<(), Error> {
+        let key = self.unique_meta_key();
+        if key.is_empty() {
+            return Ok(());
+        }
+        let physical_ids = PhysicalIds::instance();
+        if let Some(old_id) = old_meta.and_then(|meta| meta.get(&key)) {
+            physical_ids.remove(old_id);
+        }
+        if let Some(new_id) = new_meta.and_then(|meta| meta.get(&key)) {
+            if physical_ids.get(new_id).is_some() {
+                return Err(Error::TokenIdAlreadyExists);
+            }
+            physical_ids.set(new_id, token_id);
+        }
+        Ok(())
     }
-}
-
-#[no_mangle]
-fn constructor() {
-    let name = runtime::get_named_arg::<String>("name");
-    let symbol = runtime::get_named_arg::<String>("symbol");
-    let meta = runtime::get_named_arg::<Meta>("meta");
-    let admin = runtime::get_named_arg::<Key>("admin");
-    CaskToken::default().constructor(name, symbol, meta);
-    CaskToken::default().add_admin_without_checked(admin);
-}
 
-#[no_mangle]
-fn name() {
-    let ret = CaskToken::default().name();
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    /// Approving a spender lists the tokens, so uninsured tokens can't be approved while
+    /// insurance is required.
+    fn approve(&mut self, spender: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        if token_ids
+            .iter()
+            .any(|token_id| self.is_blocked_as_uninsured(token_id))
+        {
+            return Err(Error::PermissionDenied);
+        }
+        CEP47::approve(self, spender, token_ids)
+    }
 
-#[no_mangle]
-fn symbol() {
-    let ret = CaskToken::default().symbol();
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    fn transfer(&mut self, recipient: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        let caller = self.get_caller();
+        self.before_token_transfer(caller, recipient, &token_ids)?;
+        CEP47::transfer(self, recipient, token_ids.clone())?;
+        self.after_token_transfer(caller, recipient, &token_ids, REASON_TRANSFER);
+        Ok(())
+    }
 
-#[no_mangle]
-fn meta() {
-    let ret = CaskToken::default().meta();
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    fn transfer_from(
+        &mut self,
+        sender: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        let caller = self.get_caller();
+        if !self.is_admin(caller) {
+            revert(ApiError::User(20));
+        }
+        self.before_token_transfer(sender, recipient, &token_ids)?;
+        CEP47::transfer_from_internal(self, sender, recipient, token_ids.clone())?;
+        self.after_token_transfer(sender, recipient, &token_ids, REASON_TRANSFER);
+        Ok(())
+    }
 
-#[no_mangle]
-fn total_supply() {
-    let ret = CaskToken::default().total_supply();
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    fn force_transfer(
+        &mut self,
+        sender: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+        reason: String,
+    ) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        let denylist = Denylist::instance();
+        if !denylist.is_denied(&sender) || denylist.is_denied(&recipient) {
+            return Err(Error::PermissionDenied);
+        }
+        // Only the account's own restrictions are overridden; soulbound tokens can't move,
+        // and collateral only once compliance has cleared the lock.
+        if token_ids
+            .iter()
+            .any(|token_id| self.is_soulbound(token_id) || self.is_collateralized(token_id))
+        {
+            return Err(Error::PermissionDenied);
+        }
+        CEP47::transfer_from_internal(self, sender, recipient, token_ids.clone())?;
+        self.after_token_transfer(sender, recipient, &token_ids, REASON_FORCE_TRANSFER);
+        emit(&CaskEvent::ForceTransfer {
+            sender,
+            recipient,
+            token_ids,
+            reason,
+        });
+        Ok(())
+    }
 
-#[no_mangle]
-fn balance_of() {
-    let owner = runtime::get_named_arg::<Key>("owner");
-    let ret = CaskToken::default().balance_of(owner);
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    /// Grants `user` usage rights, such as tasting or display, until block time `expires`
+    /// without moving ownership. The rights end early if the token changes hands.
+    fn set_user(&mut self, token_id: TokenId, user: Key, expires: u64) -> Result<(), Error> {
+        let owner = self
+            .owner_of(token_id.clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        let caller = self.get_caller();
+        if caller != owner && Allowances::instance().get(&owner, &token_id) != Some(caller) {
+            return Err(Error::PermissionDenied);
+        }
+        self.assert_not_denylisted(owner);
+        self.assert_not_denylisted(user);
+        if expires <= u64::from(runtime::get_blocktime()) {
+            return Err(Error::WrongArguments);
+        }
+        TokenUsers::instance().set(&token_id, user, expires);
+        Ok(())
+    }
 
-#[no_mangle]
-fn owner_of() {
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let ret = CaskToken::default().owner_of(token_id);
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    fn user_of(&self, token_id: TokenId) -> Option<Key> {
+        let (user, expires) = TokenUsers::instance().get(&token_id)?;
+        if expires > u64::from(runtime::get_blocktime()) {
+            Some(user)
+        } else {
+            None
+        }
+    }
 
-#[no_mangle]
-fn get_token_by_index() {
-    let owner = runtime::get_named_arg::<Key>("owner");
-    let index = runtime::get_named_arg::<U256>("index");
-    let ret = CaskToken::default().get_token_by_index(owner, index);
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    /// Pledges the caller's token to a lender, who alone can release or liquidate it.
+    fn lock_as_collateral(&mut self, token_id: TokenId, lender: Key) -> Result<(), Error> {
+        let owner = self
+            .owner_of(token_id.clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        if self.get_caller() != owner {
+            return Err(Error::PermissionDenied);
+        }
+        // A frozen owner could otherwise shield the token from a force transfer.
+        self.assert_not_denylisted(owner);
+        self.assert_not_denylisted(lender);
+        if self.is_collateralized(&token_id)
+            || self.is_token_retired(&token_id)
+            || self.is_soulbound(&token_id)
+        {
+            return Err(Error::WrongArguments);
+        }
+        CollateralLocks::instance().set(&token_id, lender);
+        Ok(())
+    }
 
-#[no_mangle]
-fn token_meta() {
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let ret = CaskToken::default().token_meta(token_id);
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    fn release(&mut self, token_id: TokenId) -> Result<(), Error> {
+        self.assert_caller_is_lender(&token_id)?;
+        CollateralLocks::instance().remove(&token_id);
+        Ok(())
+    }
 
-#[no_mangle]
-fn token_commission() {
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let ret = CaskToken::default().token_commission(token_id);
-    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
-}
+    /// Releases a defaulted loan's collateral by moving it to `recipient`, who still has
+    /// to pass the usual transfer checks.
+    fn liquidate(&mut self, token_id: TokenId, recipient: Key) -> Result<(), Error> {
+        self.assert_caller_is_lender(&token_id)?;
+        CollateralLocks::instance().remove(&token_id);
+        let owner = self.owner_of(token_id.clone()).unwrap_or_revert();
+        let token_ids = vec![token_id];
+        self.before_token_transfer(owner, recipient, &token_ids)?;
+        CEP47::transfer_from_internal(self, owner, recipient, token_ids.clone())?;
+        self.after_token_transfer(owner, recipient, &token_ids, REASON_LIQUIDATION);
+        Ok(())
+    }
 
-#[no_mangle]
-fn set_token_meta() {
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
-    CaskToken::default()
-        .set_token_meta(token_id, token_meta)
-        .unwrap_or_revert();
-}
+    /// Lifts the lock on a denylisted owner's token so it can be force transferred.
+    fn clear_collateral_lock(&mut self, token_id: TokenId) -> Result<(), Error> {
+        if !self.is_compliance() {
+            revert(ApiError::User(20));
+        }
+        let owner = self
+            .owner_of(token_id.clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        if !self.is_collateralized(&token_id) {
+            return Err(Error::WrongArguments);
+        }
+        if !self.is_denylisted(owner) {
+            return Err(Error::PermissionDenied);
+        }
+        CollateralLocks::instance().remove(&token_id);
+        Ok(())
+    }
 
-#[no_mangle]
-fn update_token_meta() {
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let token_meta_key = runtime::get_named_arg::<String>("token_meta_key");
-    let token_meta_value = runtime::get_named_arg::<String>("token_meta_value");
-    CaskToken::default()
-        .update_token_meta(token_id, token_meta_key, token_meta_value)
-        .unwrap_or_revert();
-}
+    fn collateral_lender(&self, token_id: TokenId) -> Option<Key> {
+        CollateralLocks::instance().get(&token_id)
+    }
 
-#[no_mangle]
-fn update_token_commission() {
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let property = runtime::get_named_arg::<String>("property");
-    let account = runtime::get_named_arg::<Key>("account");
-    let mode = runtime::get_named_arg::<String>("mode");
-    let value = runtime::get_named_arg::<String>("value");
-    CaskToken::default()
-        .update_token_commission(token_id, property, mode, account, value)
-        .unwrap_or_revert();
-}
+    fn is_collateralized(&self, token_id: &TokenId) -> bool {
+        CollateralLocks::instance().get(token_id).is_some()
+    }
 
-#[no_mangle]
-fn mint() {
-    let recipient = runtime::get_named_arg::<Key>("recipient");
-    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
-    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
-    let token_commissions = runtime::get_named_arg::<Vec<Commission>>("token_commissions");
-    CaskToken::default()
-        .mint(recipient, token_ids, token_metas, token_commissions)
-        .unwrap_or_revert();
-}
+    fn assert_caller_is_lender(&self, token_id: &TokenId) -> Result<(), Error> {
+        match CollateralLocks::instance().get(token_id) {
+            Some(lender) if lender == self.get_caller() => Ok(()),
+            Some(_) => Err(Error::PermissionDenied),
+            None => Err(Error::WrongArguments),
+        }
+    }
 
-#[no_mangle]
-fn mint_copies() {
-    let recipient = runtime::get_named_arg::<Key>("recipient");
-    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
-    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
-    let token_commission = runtime::get_named_arg::<Commission>("token_commission");
-    let count = runtime::get_named_arg::<u32>("count");
-    CaskToken::default()
-        .mint_copies(recipient, token_ids, token_meta, token_commission, count)
-        .unwrap_or_revert();
-}
+    /// Checks every restriction a token move has to satisfy, whichever entry point moves it.
+    fn before_token_transfer(
+        &self,
+        sender: Key,
+        recipient: Key,
+        token_ids: &[TokenId],
+    ) -> Result<(), Error> {
+        let denylist = Denylist::instance();
+        if denylist.is_denied(&sender) || denylist.is_denied(&recipient) {
+            return Err(Error::PermissionDenied);
+        }
+        let jurisdictions_dict = Jurisdictions::instance();
+        let mut recipient_country = None;
+        let min_transfer_age: u64 = get_key(MIN_TRANSFER_AGE).unwrap_or_default();
+        for token_id in token_ids {
+            if self.is_soulbound(token_id)
+                || self.is_collateralized(token_id)
+                || self.is_token_retired(token_id)
+                || self.is_transfer_blocked_as_stale(token_id)
+                || self.is_storage_fee_overdue(token_id)
+                || self.is_blocked_as_uninsured(token_id)
+            {
+                return Err(Error::PermissionDenied);
+            }
+            // Casks without a recorded fill date are held back until one is recorded.
+            if min_transfer_age > 0 {
+                match self.cask_age(token_id.clone()) {
+                    Some(age) if age >= min_transfer_age => {}
+                    _ => return Err(Error::PermissionDenied),
+                }
+            }
+            let jurisdictions = jurisdictions_dict.get(token_id);
+            if jurisdictions.is_empty() {
+                continue;
+            }
+            if recipient_country.is_none() {
+                recipient_country = Some(self.kyc_country(recipient));
+            }
+            match recipient_country.as_ref().unwrap_or_revert() {
+                Some(country) if jurisdictions.contains(country) => {}
+                _ => return Err(Error::PermissionDenied),
+            }
+        }
+        Ok(())
+    }
 
-#[no_mangle]
-fn burn() {
-    let owner = runtime::get_named_arg::<Key>("owner");
-    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    CaskToken::default()
-        .burn(owner, token_ids)
-        .unwrap_or_revert()
-}
+    /// Bookkeeping shared by every entry point that moves tokens between accounts.
+    fn after_token_transfer(
+        &mut self,
+        sender: Key,
+        recipient: Key,
+        token_ids: &[TokenId],
+        reason: &str,
+    ) {
+        self.record_ownership(token_ids, Some(sender), Some(recipient), reason);
+        let allowances = Allowances::instance();
+        let owner_valuations = OwnerValuations::instance();
+        let token_users = TokenUsers::instance();
+        for token_id in token_ids {
+            allowances.remove(&sender, token_id);
+            token_users.remove(token_id);
+            let value = self.counted_valuation(token_id);
+            owner_valuations.sub(&sender, value);
+            owner_valuations.add(&recipient, value);
+        }
+    }
 
-#[no_mangle]
-fn transfer() {
-    let recipient = runtime::get_named_arg::<Key>("recipient");
-    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    if !CaskToken::default().is_kyc_proved(recipient) {
-        revert(ApiError::User(20));
+    fn record_ownership(
+        &mut self,
+        token_ids: &[TokenId],
+        from: Option<Key>,
+        to: Option<Key>,
+        reason: &str,
+    ) {
+        let history = OwnershipHistory::instance();
+        let timestamps = OwnershipTimestamps::instance();
+        let timestamp = u64::from(runtime::get_blocktime());
+        for token_id in token_ids {
+            timestamps.record(token_id, timestamp);
+            history.push(
+                token_id,
+                OwnershipRecord {
+                    from,
+                    to,
+                    timestamp,
+                    reason: reason.to_string(),
+                },
+            );
+        }
     }
-    CaskToken::default()
-        .transfer(recipient, token_ids)
-        .unwrap_or_revert();
-}
 
-#[no_mangle]
-fn transfer_from() {
-    let sender = runtime::get_named_arg::<Key>("sender");
-    let recipient = runtime::get_named_arg::<Key>("recipient");
-    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    let caller = CaskToken::default().get_caller();
-    if !CaskToken::default().is_admin(caller) {
-        revert(ApiError::User(20));
+    fn ownership_history(&self, token_id: TokenId) -> Vec<OwnershipRecord> {
+        OwnershipHistory::instance().get(&token_id)
     }
-    CaskToken::default()
-        .transfer_from_internal(sender, recipient, token_ids)
-        .unwrap_or_revert();
-}
 
-#[no_mangle]
-fn grant_minter() {
-    let minter = runtime::get_named_arg::<Key>("minter");
-    CaskToken::default().assert_caller_is_admin();
-    CaskToken::default().add_minter(minter);
-}
+    fn set_recovery_delay(&mut self, delay: u64) {
+        self.assert_caller_is_admin();
+        set_key(RECOVERY_DELAY, delay);
+    }
 
-#[no_mangle]
-fn revoke_minter() {
-    let minter = runtime::get_named_arg::<Key>("minter");
-    CaskToken::default().assert_caller_is_admin();
-    CaskToken::default().revoke_minter(minter);
-}
+    fn request_recovery(
+        &mut self,
+        old_owner: Key,
+        new_owner: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<u64, Error> {
+        self.assert_caller_is_admin();
+        self.assert_not_denylisted(new_owner);
+        if token_ids.is_empty() || old_owner == new_owner {
+            return Err(Error::WrongArguments);
+        }
+        for token_id in &token_ids {
+            match self.owner_of(token_id.clone()) {
+                Some(owner) if owner == old_owner => {}
+                Some(_) => return Err(Error::PermissionDenied),
+                None => return Err(Error::TokenIdDoesntExist),
+            }
+        }
+        let delay: u64 = get_key(RECOVERY_DELAY).unwrap_or_default();
+        let id: u64 = get_key(RECOVERY_COUNT).unwrap_or_default();
+        RecoveryRequests::instance().set(
+            id,
+            RecoveryRequest {
+                old_owner,
+                new_owner,
+                token_ids,
+                executable_at: u64::from(runtime::get_blocktime()) + delay,
+            },
+        );
+        set_key(RECOVERY_COUNT, id + 1);
+        Ok(id)
+    }
 
-#[no_mangle]
-fn grant_admin() {
-    let admin = runtime::get_named_arg::<Key>("admin");
-    CaskToken::default().add_admin(admin);
-}
+    fn cancel_recovery(&mut self, request_id: u64) -> Result<(), Error> {
+        let requests = RecoveryRequests::instance();
+        let request = requests.get(request_id).ok_or(Error::WrongArguments)?;
+        let caller = self.get_caller();
+        if caller != request.old_owner && !self.is_admin(caller) {
+            return Err(Error::PermissionDenied);
+        }
+        requests.remove(request_id);
+        Ok(())
+    }
 
-#[no_mangle]
-fn revoke_admin() {
-    let admin = runtime::get_named_arg::<Key>("admin");
-    CaskToken::default().disable_admin(admin);
-}
+    fn execute_recovery(&mut self, request_id: u64) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        let requests = RecoveryRequests::instance();
+        let request = requests.get(request_id).ok_or(Error::WrongArguments)?;
+        if u64::from(runtime::get_blocktime()) < request.executable_at {
+            return Err(Error::PermissionDenied);
+        }
+        requests.remove(request_id);
+        self.before_token_transfer(request.old_owner, request.new_owner, &request.token_ids)?;
+        CEP47::transfer_from_internal(
+            self,
+            request.old_owner,
+            request.new_owner,
+            request.token_ids.clone(),
+        )?;
+        self.after_token_transfer(
+            request.old_owner,
+            request.new_owner,
+            &request.token_ids,
+            REASON_RECOVERY,
+        );
+        Ok(())
+    }
 
-#[no_mangle]
-fn call() {
-    // Read arguments for the constructor call.
-    let name: String = runtime::get_named_arg("name");
-    let symbol: String = runtime::get_named_arg("symbol");
-    let meta: Meta = runtime::get_named_arg("meta");
-    let admin: Key = runtime::get_named_arg("admin");
-    let contract_name: String = runtime::get_named_arg("contract_name");
-    let kyc_package_hash: [u8; 32] = runtime::get_named_arg::<Key>(KYC_HASH)
-        .into_hash()
-        .unwrap_or_default();
+    fn post_attestation(
+        &mut self,
+        token_id: TokenId,
+        warehouse_id: String,
+        inspection_date: u64,
+        volume: u64,
+        abv: u32,
+        document_hash: String,
+    ) -> Result<(), Error> {
+        if !self.is_custodian() {
+            revert(ApiError::User(20));
+        }
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        let posted_at = u64::from(runtime::get_blocktime());
+        if inspection_date > posted_at {
+            return Err(Error::WrongArguments);
+        }
+        let attestations = Attestations::instance();
+        // Inspections are posted in order, so the last one is always the latest.
+        if let Some(latest) = attestations.get(&token_id).last() {
+            if inspection_date < latest.inspection_date {
+                return Err(Error::WrongArguments);
+            }
+        }
+        attestations.push(
+            &token_id,
+            Attestation {
+                warehouse_id,
+                inspection_date,
+                volume,
+                abv,
+                document_hash,
+                custodian: self.get_caller(),
+                posted_at,
+            },
+        );
+        Ok(())
+    }
 
-    let mut named_keys = NamedKeys::new();
-    named_keys.insert(KYC_HASH.into(), storage::new_uref(kyc_package_hash).into());
+    fn token_attestations(&self, token_id: TokenId) -> Vec<Attestation> {
+        Attestations::instance().get(&token_id)
+    }
 
-    let (contract_hash, _) = storage::new_contract(
-        get_entry_points(),
-        Some(named_keys),
-        Some(String::from(&format!(
-            "{}_contract_package_hash",
-            contract_name
-        ))),
-        Some(format!("{}_package_access_token", contract_name)),
-    );
+    fn latest_attestation(&self, token_id: TokenId) -> Option<Attestation> {
+        self.token_attestations(token_id).pop()
+    }
 
-    // Prepare constructor args
-    let constructor_args = runtime_args! {
-        "name" => name,
-        "symbol" => symbol,
-        "meta" => meta,
-        "admin" => admin
-    };
+    fn set_attestation_max_age(&mut self, max_age: u64) {
+        self.assert_caller_is_admin();
+        set_key(ATTESTATION_MAX_AGE, max_age);
+    }
 
-    let package_hash = ContractPackageHash::new(
-        runtime::get_key(&format!("{}_contract_package_hash", contract_name))
-            .unwrap_or_revert()
-            .into_hash()
-            .unwrap_or_revert(),
-    );
+    fn set_block_stale_transfers(&mut self, block: bool) {
+        self.assert_caller_is_admin();
+        set_key(BLOCK_STALE_TRANSFERS, block);
+    }
 
-    // Add the constructor group to the package hash with a single URef.
-    let constructor_access: URef =
-        storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
-            .unwrap_or_revert()
-            .pop()
-            .unwrap_or_revert();
+    /// A token is stale when its latest inspection, if any, is older than the max age.
+    /// A max age of 0 disables the check.
+    fn is_attestation_stale(&self, token_id: TokenId) -> bool {
+        let max_age: u64 = get_key(ATTESTATION_MAX_AGE).unwrap_or_default();
+        if max_age == 0 {
+            return false;
+        }
+        let now = u64::from(runtime::get_blocktime());
+        match self.latest_attestation(token_id) {
+            Some(attestation) => now.saturating_sub(attestation.inspection_date) > max_age,
+            None => true,
+        }
+    }
 
-    // Call the constructor entry point
-    let _: () = runtime::call_contract(contract_hash, "constructor", constructor_args);
+    fn is_transfer_blocked_as_stale(&self, token_id: &TokenId) -> bool {
+        get_key(BLOCK_STALE_TRANSFERS).unwrap_or_default()
+            && self.is_attestation_stale(token_id.clone())
+    }
 
-    // Remove all URefs from the constructor group, so no one can call it for the second time.
-    let mut urefs = BTreeSet::new();
-    urefs.insert(constructor_access);
-    storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
-        .unwrap_or_revert();
+    fn token_regauges(&self, token_id: TokenId) -> Vec<Regauge> {
+        Regauges::instance().get(&token_id)
+    }
 
-    // Store contract in the account's named keys.
-    runtime::put_key(
-        &format!("{}_contract_hash", contract_name),
-        contract_hash.into(),
-    );
-    runtime::put_key(
-        &format!("{}_contract_hash_wrapped", contract_name),
-        storage::new_uref(contract_hash).into(),
-    );
-    runtime::put_key(
-        &format!("{}_package_hash_wrapped", contract_name),
-        storage::new_uref(package_hash).into(),
-    );
-}
+    fn latest_regauge(&self, token_id: TokenId) -> Option<Regauge> {
+        self.token_regauges(token_id).pop()
+    }
 
-fn get_entry_points() -> EntryPoints {
-    let mut entry_points = EntryPoints::new();
+    /// Logs a custodian's regauge. Evaporation only ever takes alcohol away, so a
+    /// regauge can't report more litres of pure alcohol than the previous one.
+    fn add_regauge(
+        &mut self,
+        token_id: TokenId,
+        date: u64,
+        bulk: u64,
+        abv: u32,
+        lpa: u64,
+    ) -> Result<(), Error> {
+        if !self.is_custodian() {
+            revert(ApiError::User(20));
+        }
+        self.push_regauge(token_id, date, bulk, abv, lpa, false)
+    }
+
+    /// Logs an admin correction, which may raise the litres of pure alcohol.
+    fn correct_regauge(
+        &mut self,
+        token_id: TokenId,
+        date: u64,
+        bulk: u64,
+        abv: u32,
+        lpa: u64,
+    ) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        self.push_regauge(token_id, date, bulk, abv, lpa, true)
+    }
+
+    fn push_regauge(
+        &mut self,
+        token_id: TokenId,
+        date: u64,
+        bulk: u64,
+        abv: u32,
+        lpa: u64,
+        correction: bool,
+    ) -> Result<(), Error> {
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        if date > u64::from(runtime::get_blocktime()) {
+            return Err(Error::WrongArguments);
+        }
+        let regauges = Regauges::instance();
+        if let Some(latest) = regauges.get(&token_id).last() {
+            if date < latest.date || (!correction && lpa > latest.lpa) {
+                return Err(Error::WrongArguments);
+            }
+        }
+        regauges.push(
+            &token_id,
+            Regauge {
+                date,
+                bulk,
+                abv,
+                lpa,
+                custodian: self.get_caller(),
+                correction,
+            },
+        );
+        Ok(())
+    }
+
+    fn token_documents(&self, token_id: TokenId) -> Vec<TokenDocument> {
+        TokenDocuments::instance().get(&token_id)
+    }
+
+    fn add_token_document(
+        &mut self,
+        token_id: TokenId,
+        doc_type: String,
+        hash: String,
+        uri: String,
+    ) -> Result<u32, Error> {
+        self.assert_caller_is_document_manager();
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        let documents_dict = TokenDocuments::instance();
+        let mut documents = documents_dict.get(&token_id);
+        let index = self.push_token_document(&mut documents, doc_type, hash, uri)?;
+        documents_dict.set(&token_id, documents);
+        Ok(index)
+    }
+
+    fn revoke_token_document(&mut self, token_id: TokenId, index: u32) -> Result<(), Error> {
+        self.assert_caller_is_document_manager();
+        let documents_dict = TokenDocuments::instance();
+        let mut documents = documents_dict.get(&token_id);
+        match documents.get_mut(index as usize) {
+            Some(document) if !document.revoked => document.revoked = true,
+            _ => return Err(Error::WrongArguments),
+        }
+        documents_dict.set(&token_id, documents);
+        Ok(())
+    }
+
+    /// Revokes a document and appends its successor of the same type.
+    fn replace_token_document(
+        &mut self,
+        token_id: TokenId,
+        index: u32,
+        hash: String,
+        uri: String,
+    ) -> Result<u32, Error> {
+        self.assert_caller_is_document_manager();
+        let documents_dict = TokenDocuments::instance();
+        let mut documents = documents_dict.get(&token_id);
+        let doc_type = match documents.get(index as usize) {
+            Some(document) if !document.revoked => document.doc_type.clone(),
+            _ => return Err(Error::WrongArguments),
+        };
+        let new_index = self.push_token_document(&mut documents, doc_type, hash, uri)?;
+        let document = &mut documents[index as usize];
+        document.revoked = true;
+        document.replaced_by = Some(new_index);
+        documents_dict.set(&token_id, documents);
+        Ok(new_index)
+    }
+
+    fn push_token_document(
+        &self,
+        documents: &mut Vec<TokenDocument>,
+        doc_type: String,
+        hash: String,
+        uri: String,
+    ) -> Result<u32, Error> {
+        if !is_blake2b_hex(&hash) {
+            return Err(Error::WrongArguments);
+        }
+        documents.push(TokenDocument {
+            doc_type,
+            hash,
+            uri,
+            added_by: self.get_caller(),
+            added_at: u64::from(runtime::get_blocktime()),
+            revoked: false,
+            replaced_by: None,
+        });
+        Ok((documents.len() - 1) as u32)
+    }
+
+    fn assert_caller_is_document_manager(&self) {
+        let caller = self.get_caller();
+        if !self.is_minter() && !self.is_admin(caller) {
+            revert(ApiError::User(20));
+        }
+    }
+
+    fn fill_timestamp(&self, token_id: TokenId) -> Option<u64> {
+        FillTimestamps::instance().get(&token_id)
+    }
+
+    /// Minters record the fill date once; only admins can correct it afterwards, since
+    /// an earlier date releases the cask from `min_transfer_age`.
+    fn set_fill_timestamp(&mut self, token_id: TokenId, fill_timestamp: u64) -> Result<(), Error> {
+        let caller = self.get_caller();
+        let is_admin = self.is_admin(caller);
+        if !self.is_minter() && !is_admin {
+            revert(ApiError::User(20));
+        }
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        if fill_timestamp > u64::from(runtime::get_blocktime()) {
+            return Err(Error::WrongArguments);
+        }
+        let fill_timestamps = FillTimestamps::instance();
+        if fill_timestamps.get(&token_id).is_some() && !is_admin {
+            return Err(Error::PermissionDenied);
+        }
+        fill_timestamps.set(&token_id, fill_timestamp);
+        Ok(())
+    }
+
+    /// Milliseconds since the cask was filled, if its fill date is recorded.
+    fn cask_age(&self, token_id: TokenId) -> Option<u64> {
+        let fill_timestamp = self.fill_timestamp(token_id)?;
+        Some(u64::from(runtime::get_blocktime()).saturating_sub(fill_timestamp))
+    }
+
+    /// Block time at which the cask turns `years` old.
+    fn matures_at(&self, token_id: TokenId, years: u32) -> Option<u64> {
+        let fill_timestamp = self.fill_timestamp(token_id)?;
+        Some(fill_timestamp.saturating_add(u64::from(years).saturating_mul(YEAR)))
+    }
+
+    fn set_min_transfer_age(&mut self, min_age: u64) {
+        self.assert_caller_is_admin();
+        set_key(MIN_TRANSFER_AGE, min_age);
+    }
+
+    fn token_insurance(&self, token_id: TokenId) -> Option<InsuranceRecord> {
+        Insurance::instance().get(&token_id)
+    }
+
+    fn set_token_insurance(
+        &mut self,
+        token_id: TokenId,
+        policy_hash: String,
+        insurer: String,
+        coverage_value: U512,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        if !self.is_compliance() {
+            revert(ApiError::User(20));
+        }
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        if !is_blake2b_hex(&policy_hash) {
+            return Err(Error::WrongArguments);
+        }
+        Insurance::instance().set(
+            &token_id,
+            InsuranceRecord {
+                policy_hash,
+                insurer,
+                coverage_value,
+                expiry,
+            },
+        );
+        Ok(())
+    }
+
+    fn remove_token_insurance(&mut self, token_id: TokenId) {
+        if !self.is_compliance() {
+            revert(ApiError::User(20));
+        }
+        Insurance::instance().remove(&token_id);
+    }
+
+    fn set_require_insurance(&mut self, require: bool) {
+        self.assert_caller_is_admin();
+        set_key(REQUIRE_INSURANCE, require);
+    }
+
+    fn is_insured_until(&self, token_id: &TokenId, timestamp: u64) -> bool {
+        Insurance::instance()
+            .get(token_id)
+            .map_or(false, |record| record.expiry > timestamp)
+    }
+
+    fn is_blocked_as_uninsured(&self, token_id: &TokenId) -> bool {
+        get_key::<bool>(REQUIRE_INSURANCE).unwrap_or_default()
+            && !self.is_insured_until(token_id, u64::from(runtime::get_blocktime()))
+    }
+
+    /// Insured tokens of a page of the global index whose cover ends by `timestamp`.
+    fn insurance_expiring_before(&self, timestamp: u64, offset: U256, limit: u32) -> Vec<TokenId> {
+        let insurance = Insurance::instance();
+        self.all_tokens(offset, limit)
+            .into_iter()
+            .filter(|token_id| {
+                insurance
+                    .get(token_id)
+                    .map_or(false, |record| record.expiry <= timestamp)
+            })
+            .collect()
+    }
+
+    fn token_valuations(&self, token_id: TokenId) -> Vec<Valuation> {
+        Valuations::instance().get(&token_id)
+    }
+
+    fn latest_valuation(&self, token_id: TokenId) -> Option<Valuation> {
+        self.token_valuations(token_id).pop()
+    }
+
+    fn owner_valuation(&self, owner: Key) -> U512 {
+        OwnerValuations::instance().get(&owner)
+    }
+
+    /// Latest value of a token as counted in its owner's aggregate. Retired tokens live on
+    /// in their children, so they no longer count.
+    fn counted_valuation(&self, token_id: &TokenId) -> U512 {
+        if self.is_token_retired(token_id) {
+            return U512::zero();
+        }
+        Valuations::instance().latest_value(token_id)
+    }
+
+    /// Records an oracle's valuation and moves the owner's aggregate from the previous
+    /// latest value to the new one.
+    fn publish_valuation(
+        &mut self,
+        token_id: TokenId,
+        value: U512,
+        timestamp: u64,
+        source: String,
+    ) -> Result<(), Error> {
+        if !self.is_oracle() {
+            revert(ApiError::User(20));
+        }
+        let owner = self
+            .owner_of(token_id.clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        if timestamp > u64::from(runtime::get_blocktime()) || self.is_token_retired(&token_id) {
+            return Err(Error::WrongArguments);
+        }
+        let valuations = Valuations::instance();
+        if let Some(previous) = valuations.get(&token_id).last() {
+            if timestamp < previous.timestamp {
+                return Err(Error::WrongArguments);
+            }
+        }
+        let owner_valuations = OwnerValuations::instance();
+        owner_valuations.sub(&owner, valuations.latest_value(&token_id));
+        owner_valuations.add(&owner, value);
+        valuations.push(
+            &token_id,
+            Valuation {
+                value,
+                timestamp,
+                source,
+                oracle: self.get_caller(),
+            },
+        );
+        Ok(())
+    }
+
+    fn token_jurisdictions(&self, token_id: TokenId) -> Vec<String> {
+        Jurisdictions::instance().get(&token_id)
+    }
+
+    fn set_token_jurisdictions(
+        &mut self,
+        token_id: TokenId,
+        jurisdictions: Vec<String>,
+    ) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        if jurisdictions.is_empty() {
+            Jurisdictions::instance().remove(&token_id);
+        } else {
+            Jurisdictions::instance().set(&token_id, jurisdictions);
+        }
+        Ok(())
+    }
+
+    fn is_denylisted(&self, account: Key) -> bool {
+        Denylist::instance().is_denied(&account)
+    }
+
+    fn assert_not_denylisted(&self, account: Key) {
+        if self.is_denylisted(account) {
+            revert(Error::PermissionDenied);
+        }
+    }
+
+    fn add_to_denylist(&mut self, account: Key) {
+        if !self.is_compliance() {
+            revert(ApiError::User(20));
+        }
+        Denylist::instance().deny(&account);
+    }
+
+    fn remove_from_denylist(&mut self, account: Key) {
+        if !self.is_compliance() {
+            revert(ApiError::User(20));
+        }
+        Denylist::instance().allow(&account);
+    }
+
+    fn update_token_commission(
+        &mut self,
+        token_id: TokenId,
+        property: String,
+        mode: String,
+        account: Key,
+        value: String,
+    ) -> Result<(), Error> {
+        let caller = CaskToken::default().get_caller();
+        if !CaskToken::default().is_admin(caller) {
+            revert(ApiError::User(20));
+        }
+        self.set_token_commission(token_id, property, mode, account, value)
+            .unwrap_or_revert();
+        Ok(())
+    }
+
+    /// Moves the token from the seller to the buyer and pays for it from the purse. Only
+    /// the seller or the account they approved for the token, such as a marketplace, can
+    /// settle. Rates are whole percentages of `amount`. The sale reverts with
+    /// `WrongArguments` if any rate of the token's commission is not an integer string.
+    fn settle_sale(
+        &mut self,
+        token_id: TokenId,
+        seller: Key,
+        buyer: Key,
+        amount: U512,
+        purse: URef,
+    ) -> Result<(), Error> {
+        let owner = self
+            .owner_of(token_id.clone())
+            .ok_or(Error::TokenIdDoesntExist)?;
+        let caller = self.get_caller();
+        if owner != seller
+            || (caller != seller && Allowances::instance().get(&seller, &token_id) != Some(caller))
+        {
+            return Err(Error::PermissionDenied);
+        }
+        let token_ids = vec![token_id.clone()];
+        self.before_token_transfer(seller, buyer, &token_ids)?;
+        system::transfer_from_purse_to_purse(purse, self.contract_purse(), amount, None)
+            .unwrap_or_revert();
+
+        let (commission, _) = self.token_commission(token_id).unwrap_or_revert();
+        let mut paid_out = U512::zero();
+        for (name, rate) in commission.iter() {
+            let property = match name.strip_suffix("_rate") {
+                Some(property) => property,
+                None => continue,
+            };
+            let account = commission
+                .get(&format!("{}_account", property))
+                .and_then(|account| commission_account(account))
+                .ok_or(Error::WrongArguments)?;
+            let rate = U512::from_dec_str(rate).map_err(|_| Error::WrongArguments)?;
+            let share = amount * rate / U512::from(100);
+            self.credit(account, share);
+            paid_out += share;
+        }
+        if paid_out > amount {
+            return Err(Error::WrongArguments);
+        }
+        self.credit(seller, amount - paid_out);
+        CEP47::transfer_from_internal(self, seller, buyer, token_ids.clone())?;
+        self.after_token_transfer(seller, buyer, &token_ids, REASON_SALE);
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: U512, purse: Option<URef>) -> Result<(), Error> {
+        let caller = self.get_caller();
+        let balances = BalancesOwed::instance();
+        let owed = balances.get(&caller);
+        if amount > owed {
+            return Err(Error::WrongArguments);
+        }
+        balances.set(&caller, owed - amount);
+        match (caller, purse) {
+            (_, Some(purse)) => {
+                system::transfer_from_purse_to_purse(self.contract_purse(), purse, amount, None)
+                    .unwrap_or_revert();
+            }
+            (Key::Account(account_hash), None) => {
+                system::transfer_from_purse_to_account(
+                    self.contract_purse(),
+                    account_hash,
+                    amount,
+                    None,
+                )
+                .unwrap_or_revert();
+            }
+            _ => return Err(Error::WrongArguments),
+        }
+        Ok(())
+    }
+
+    fn balance_owed(&self, key: Key) -> U512 {
+        BalancesOwed::instance().get(&key)
+    }
+
+    fn credit(&mut self, key: Key, amount: U512) {
+        if amount.is_zero() {
+            return;
+        }
+        let balances = BalancesOwed::instance();
+        let owed = balances.get(&key);
+        balances.set(&key, owed + amount);
+    }
+
+    /// Checkpoints the fee index first, so fees accrued so far keep the old rate.
+    fn set_storage_fee(&mut self, fee: U512) {
+        self.assert_caller_is_admin();
+        set_key(STORAGE_FEE_INDEX, self.storage_fee_index());
+        set_key(STORAGE_FEE_UPDATED_AT, u64::from(runtime::get_blocktime()));
+        set_key(STORAGE_FEE, fee);
+    }
+
+    /// Sum of the yearly fee times the milliseconds it applied for, since deployment.
+    fn storage_fee_index(&self) -> U512 {
+        let index: U512 = get_key(STORAGE_FEE_INDEX).unwrap_or_default();
+        let fee: U512 = get_key(STORAGE_FEE).unwrap_or_default();
+        let updated_at: u64 = get_key(STORAGE_FEE_UPDATED_AT).unwrap_or_default();
+        let elapsed = u64::from(runtime::get_blocktime()).saturating_sub(updated_at);
+        index + fee * U512::from(elapsed)
+    }
+
+    fn set_storage_fee_grace_period(&mut self, grace_period: u64) {
+        self.assert_caller_is_admin();
+        set_key(STORAGE_FEE_GRACE_PERIOD, grace_period);
+    }
+
+    fn set_block_unpaid_transfers(&mut self, block: bool) {
+        self.assert_caller_is_admin();
+        set_key(BLOCK_UNPAID_TRANSFERS, block);
+    }
+
+    fn set_treasury(&mut self, treasury: Key) {
+        self.assert_caller_is_admin();
+        set_key(TREASURY, Some(treasury));
+    }
+
+    /// Storage fees of a token since they were last settled, at the yearly fee in force
+    /// over each part of that time, plus those fixed when it was retired.
+    fn storage_fees_owed(&self, token_id: TokenId) -> U512 {
+        if self.owner_of(token_id.clone()).is_none() {
+            return U512::zero();
+        }
+        let accrued = FeeIndexesPaid::instance()
+            .get(&token_id)
+            .map_or_else(U512::zero, |paid_index| {
+                self.storage_fee_index().saturating_sub(paid_index) / U512::from(YEAR)
+            });
+        FeesCarried::instance().get(&token_id) + accrued
+    }
+
+    /// Settles a token's storage fees from the purse, crediting the treasury.
+    fn pay_storage_fees(&mut self, token_id: TokenId, purse: URef) -> Result<U512, Error> {
+        if self.owner_of(token_id.clone()).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        let treasury: Key = get_key::<Option<Key>>(TREASURY)
+            .flatten()
+            .ok_or(Error::WrongArguments)?;
+        let amount = self.storage_fees_owed(token_id.clone());
+        if !amount.is_zero() {
+            system::transfer_from_purse_to_purse(purse, self.contract_purse(), amount, None)
+                .unwrap_or_revert();
+            self.credit(treasury, amount);
+        }
+        FeesPaidUntil::instance().set(&token_id, u64::from(runtime::get_blocktime()));
+        FeesCarried::instance().remove(&token_id);
+        let fee_indexes_paid = FeeIndexesPaid::instance();
+        if fee_indexes_paid.get(&token_id).is_some() {
+            fee_indexes_paid.set(&token_id, self.storage_fee_index());
+        }
+        Ok(amount)
+    }
+
+    /// Burning a token would write off its storage fees, so they have to be settled first.
+    fn assert_storage_fees_settled(&self, token_ids: &[TokenId]) -> Result<(), Error> {
+        if token_ids
+            .iter()
+            .any(|token_id| !self.storage_fees_owed(token_id.clone()).is_zero())
+        {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Whether unpaid storage fees are older than the grace period and block the token.
+    fn is_storage_fee_overdue(&self, token_id: &TokenId) -> bool {
+        if !get_key::<bool>(BLOCK_UNPAID_TRANSFERS).unwrap_or_default() {
+            return false;
+        }
+        let grace_period: u64 = get_key(STORAGE_FEE_GRACE_PERIOD).unwrap_or_default();
+        let paid_until = FeesPaidUntil::instance().get(token_id);
+        u64::from(runtime::get_blocktime()) > paid_until.saturating_add(grace_period)
+            && !self.storage_fees_owed(token_id.clone()).is_zero()
+    }
+
+    fn contract_purse(&self) -> URef {
+        runtime::get_key(CONTRACT_PURSE)
+            .unwrap_or_revert()
+            .into_uref()
+            .unwrap_or_revert()
+    }
+
+    fn propose(&mut self, action: String, args: Vec<u8>, deadline: u64) -> Result<u64, Error> {
+        if !GUARDABLE_ENTRY_POINTS.contains(&action.as_str()) {
+            return Err(Error::WrongArguments);
+        }
+        let proposal_id = self.add_proposal(action, args, deadline)?;
+        self.execute_if_approved(proposal_id)?;
+        Ok(proposal_id)
+    }
+
+    fn approve_proposal(&mut self, proposal_id: u64) -> Result<(), Error> {
+        self.add_approval(proposal_id)?;
+        self.execute_if_approved(proposal_id)
+    }
+
+    fn execute_if_approved(&mut self, proposal_id: u64) -> Result<(), Error> {
+        match self.take_approved_proposal(proposal_id) {
+            Some(proposal) => self.execute_proposal(proposal),
+            None => Ok(()),
+        }
+    }
+
+    fn execute_proposal(&mut self, proposal: Proposal) -> Result<(), Error> {
+        let args = proposal.args.as_slice();
+        let args = match proposal.action.as_str() {
+            "transfer_from" => {
+                let (sender, args) = next_arg::<Key>(args)?;
+                let (recipient, args) = next_arg::<Key>(args)?;
+                let (token_ids, args) = next_arg::<Vec<TokenId>>(args)?;
+                self.transfer_from(sender, recipient, token_ids)?;
+                args
+            }
+            "force_transfer" => {
+                let (sender, args) = next_arg::<Key>(args)?;
+                let (recipient, args) = next_arg::<Key>(args)?;
+                let (token_ids, args) = next_arg::<Vec<TokenId>>(args)?;
+                let (reason, args) = next_arg::<String>(args)?;
+                self.force_transfer(sender, recipient, token_ids, reason)?;
+                args
+            }
+            "request_recovery" => {
+                let (old_owner, args) = next_arg::<Key>(args)?;
+                let (new_owner, args) = next_arg::<Key>(args)?;
+                let (token_ids, args) = next_arg::<Vec<TokenId>>(args)?;
+                self.request_recovery(old_owner, new_owner, token_ids)?;
+                args
+            }
+            "execute_recovery" => {
+                let (request_id, args) = next_arg::<u64>(args)?;
+                self.execute_recovery(request_id)?;
+                args
+            }
+            "update_token_commission" => {
+                let (token_id, args) = next_arg::<TokenId>(args)?;
+                let (property, args) = next_arg::<String>(args)?;
+                let (account, args) = next_arg::<Key>(args)?;
+                let (mode, args) = next_arg::<String>(args)?;
+                let (value, args) = next_arg::<String>(args)?;
+                self.update_token_commission(token_id, property, mode, account, value)?;
+                args
+            }
+            "set_default_commission" => {
+                let (commission, args) = next_arg::<Commission>(args)?;
+                self.set_default_commission(commission);
+                args
+            }
+            "update_collection" => {
+                let (collection_id, args) = next_arg::<String>(args)?;
+                let (name, args) = next_arg::<String>(args)?;
+                let (meta, args) = next_arg::<Meta>(args)?;
+                let (commission, args) = next_arg::<Commission>(args)?;
+                self.update_collection(collection_id, name, meta, commission)?;
+                args
+            }
+            "clear_token_commission" => {
+                let (token_id, args) = next_arg::<TokenId>(args)?;
+                self.clear_token_commission(token_id)?;
+                args
+            }
+            "grant_admin" => {
+                let (admin, args) = next_arg::<Key>(args)?;
+                self.assert_not_denylisted(admin);
+                self.add_admin(admin);
+                args
+            }
+            "revoke_admin" => {
+                let (admin, args) = next_arg::<Key>(args)?;
+                self.disable_admin(admin);
+                args
+            }
+            "grant_minter" => {
+                let (minter, args) = next_arg::<Key>(args)?;
+                self.assert_not_denylisted(minter);
+                self.add_minter(minter);
+                args
+            }
+            "grant_compliance" => {
+                let (compliance, args) = next_arg::<Key>(args)?;
+                self.assert_not_denylisted(compliance);
+                self.add_compliance(compliance);
+                args
+            }
+            "burn" => {
+                let (owner, args) = next_arg::<Key>(args)?;
+                let (token_ids, args) = next_arg::<Vec<TokenId>>(args)?;
+                self.burn(owner, token_ids)?;
+                args
+            }
+            "merge_tokens" => {
+                let (token_ids, args) = next_arg::<Vec<TokenId>>(args)?;
+                let (new_meta, args) = next_arg::<Meta>(args)?;
+                let (commission_policy, args) = next_arg::<String>(args)?;
+                self.merge_tokens(token_ids, new_meta, commission_policy)?;
+                args
+            }
+            "set_quorum_threshold" => {
+                let (threshold, args) = next_arg::<u32>(args)?;
+                QuorumControl::set_quorum_threshold(self, threshold)?;
+                args
+            }
+            "set_guarded_entry_point" => {
+                let (entry_point, args) = next_arg::<String>(args)?;
+                let (guarded, args) = next_arg::<bool>(args)?;
+                self.set_guarded_entry_point(entry_point, guarded);
+                args
+            }
+            "set_recovery_delay" => {
+                let (delay, args) = next_arg::<u64>(args)?;
+                self.set_recovery_delay(delay);
+                args
+            }
+            "set_storage_fee" => {
+                let (fee, args) = next_arg::<U512>(args)?;
+                self.set_storage_fee(fee);
+                args
+            }
+            "set_treasury" => {
+                let (treasury, args) = next_arg::<Key>(args)?;
+                self.set_treasury(treasury);
+                args
+            }
+            "raise_supply_cap" => {
+                // A lone admin could otherwise raise the cap by proposing to themselves.
+                if self.quorum_threshold() <= 1 {
+                    return Err(Error::PermissionDenied);
+                }
+                let (collection_id, args) = next_arg::<Option<String>>(args)?;
+                let (cap, args) = next_arg::<U256>(args)?;
+                self.update_supply_cap(collection_id, cap, true)?;
+                args
+            }
+            _ => return Err(Error::WrongArguments),
+        };
+        if !args.is_empty() {
+            return Err(Error::WrongArguments);
+        }
+        Ok(())
+    }
+
+    /// Quorum settings can only be changed by a proposal once a quorum is required.
+    fn assert_quorum_not_required(&self) {
+        self.assert_caller_is_admin();
+        if self.quorum_threshold() > 1 {
+            revert(Error::PermissionDenied);
+        }
+    }
+
+    fn get_kyc_hash(&self) -> ContractPackageHash {
+        let uref = runtime::get_key(KYC_HASH)
+            .unwrap_or_revert_with(ApiError::User(100))
+            .into_uref()
+            .unwrap_or_revert_with(ApiError::User(101));
+
+        storage::read(uref)
+            .unwrap_or_revert_with(ApiError::User(102))
+            .unwrap_or_revert_with(ApiError::User(103))
+    }
+
+    fn is_kyc_proved(&self, account: Key) -> bool {
+        runtime::get_caller();
+        runtime::call_versioned_contract::<bool>(
+            self.get_kyc_hash(),
+            None,
+            "is_kyc_proved",
+            runtime_args! {"account" => account, "index" => Option::<U256>::None},
+        );
+        true
+    }
+
+    /// Reads the country recorded in the account's first KYC token, if any.
+    fn kyc_country(&self, account: Key) -> Option<String> {
+        let kyc_token_id = runtime::call_versioned_contract::<Option<TokenId>>(
+            self.get_kyc_hash(),
+            None,
+            "get_token_by_index",
+            runtime_args! {"owner" => account, "index" => U256::zero()},
+        )?;
+        let kyc_meta = runtime::call_versioned_contract::<Option<Meta>>(
+            self.get_kyc_hash(),
+            None,
+            "token_meta",
+            runtime_args! {"token_id" => kyc_token_id},
+        )?;
+        kyc_meta.get(KYC_COUNTRY).cloned()
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let name = runtime::get_named_arg::<String>("name");
+    let symbol = runtime::get_named_arg::<String>("symbol");
+    let meta = runtime::get_named_arg::<Meta>("meta");
+    let admin = runtime::get_named_arg::<Key>("admin");
+    CaskToken::default().constructor(name, symbol, meta);
+    CaskToken::default().add_admin_without_checked(admin);
+}
+
+#[no_mangle]
+fn name() {
+    let ret = CaskToken::default().name();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn symbol() {
+    let ret = CaskToken::default().symbol();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn meta() {
+    let ret = CaskToken::default().meta();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn total_supply() {
+    let ret = CaskToken::default().total_supply();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn balance_of() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let ret = CaskToken::default().balance_of(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn owner_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().owner_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn get_token_by_index() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let index = runtime::get_named_arg::<U256>("index");
+    let ret = CaskToken::default().get_token_by_index(owner, index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn tokens_of() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let offset = runtime::get_named_arg::<U256>("offset");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = CaskToken::default().tokens_of(owner, offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_by_index() {
+    let index = runtime::get_named_arg::<U256>("index");
+    let ret = CaskToken::default().token_by_index(index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn all_tokens() {
+    let offset = runtime::get_named_arg::<U256>("offset");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = CaskToken::default().all_tokens(offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_meta(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_commission() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_commission(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_metas() {
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let ret = CaskToken::default().token_metas(token_ids);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_commissions() {
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let ret = CaskToken::default().token_commissions(token_ids);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_info() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_info(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn default_commission() {
+    let ret = CaskToken::default().default_commission();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_default_commission() {
+    let commission = runtime::get_named_arg::<Commission>("commission");
+    CaskToken::default().assert_not_guarded("set_default_commission");
+    CaskToken::default().set_default_commission(commission);
+}
+
+#[no_mangle]
+fn clear_token_commission() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    CaskToken::default().assert_not_guarded("clear_token_commission");
+    CaskToken::default()
+        .clear_token_commission(token_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn set_token_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    CaskToken::default()
+        .set_token_meta(token_id, token_meta)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn update_token_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let token_meta_key = runtime::get_named_arg::<String>("token_meta_key");
+    let token_meta_value = runtime::get_named_arg::<String>("token_meta_value");
+    CaskToken::default()
+        .update_token_meta(token_id, token_meta_key, token_meta_value)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn update_token_commission() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let property = runtime::get_named_arg::<String>("property");
+    let account = runtime::get_named_arg::<Key>("account");
+    let mode = runtime::get_named_arg::<String>("mode");
+    let value = runtime::get_named_arg::<String>("value");
+    CaskToken::default().assert_not_guarded("update_token_commission");
+    CaskToken::default()
+        .update_token_commission(token_id, property, mode, account, value)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_id_keys() {
+    let ret = CaskToken::default().token_id_keys();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_id_keys() {
+    let keys = runtime::get_named_arg::<Vec<String>>("keys");
+    CaskToken::default().set_token_id_keys(keys);
+}
+
+#[no_mangle]
+fn set_unique_meta_key() {
+    let key = runtime::get_named_arg::<String>("key");
+    CaskToken::default()
+        .set_unique_meta_key(key)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_by_physical_id() {
+    let physical_id = runtime::get_named_arg::<String>("physical_id");
+    let ret = CaskToken::default().token_by_physical_id(physical_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mint() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let token_commissions = runtime::get_named_arg::<Vec<Commission>>("token_commissions");
+    CaskToken::default()
+        .mint(recipient, token_ids, token_metas, token_commissions)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_soulbound() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let token_commissions = runtime::get_named_arg::<Vec<Commission>>("token_commissions");
+    CaskToken::default()
+        .mint_soulbound(recipient, token_ids, token_metas, token_commissions)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn is_soulbound() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().is_soulbound(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn create_collection() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let name = runtime::get_named_arg::<String>("name");
+    let meta = runtime::get_named_arg::<Meta>("meta");
+    let commission = runtime::get_named_arg::<Commission>("commission");
+    let cap = runtime::get_named_arg::<Option<U256>>("cap");
+    CaskToken::default()
+        .create_collection(collection_id, name, meta, commission, cap)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn update_collection() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let name = runtime::get_named_arg::<String>("name");
+    let meta = runtime::get_named_arg::<Meta>("meta");
+    let commission = runtime::get_named_arg::<Commission>("commission");
+    CaskToken::default().assert_not_guarded("update_collection");
+    CaskToken::default()
+        .update_collection(collection_id, name, meta, commission)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn grant_collection_minter() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let minter = runtime::get_named_arg::<Key>("minter");
+    CaskToken::default()
+        .grant_collection_minter(collection_id, minter)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn revoke_collection_minter() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let minter = runtime::get_named_arg::<Key>("minter");
+    CaskToken::default()
+        .revoke_collection_minter(collection_id, minter)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_to_collection() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let token_commissions = runtime::get_named_arg::<Vec<Commission>>("token_commissions");
+    CaskToken::default()
+        .mint_to_collection(
+            collection_id,
+            recipient,
+            token_ids,
+            token_metas,
+            token_commissions,
+        )
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn collection() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let ret = CaskToken::default().collection(collection_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn collection_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().collection_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn collection_supply() {
+    let collection_id = runtime::get_named_arg::<String>("collection_id");
+    let ret = CaskToken::default().collection_supply(collection_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_supply_cap() {
+    let collection_id = runtime::get_named_arg::<Option<String>>("collection_id");
+    let cap = runtime::get_named_arg::<U256>("cap");
+    CaskToken::default()
+        .set_supply_cap(collection_id, cap)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn max_supply() {
+    let ret = CaskToken::default().max_supply();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn remaining_supply() {
+    let collection_id = runtime::get_named_arg::<Option<String>>("collection_id");
+    let ret = CaskToken::default().remaining_supply(collection_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mint_copies() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    let token_commission = runtime::get_named_arg::<Commission>("token_commission");
+    let count = runtime::get_named_arg::<u32>("count");
+    CaskToken::default()
+        .mint_copies(recipient, token_ids, token_meta, token_commission, count)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn split_into_bottles() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let count = runtime::get_named_arg::<u32>("count");
+    let bottle_meta = runtime::get_named_arg::<Meta>("bottle_meta");
+    let ret = CaskToken::default()
+        .split_into_bottles(token_id, count, bottle_meta)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn children_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().children_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn parent_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().parent_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn merge_tokens() {
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let new_meta = runtime::get_named_arg::<Meta>("new_meta");
+    let commission_policy = runtime::get_named_arg::<String>("commission_policy");
+    CaskToken::default().assert_not_guarded("merge_tokens");
+    let ret = CaskToken::default()
+        .merge_tokens(token_ids, new_meta, commission_policy)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn lineage_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().lineage_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn burn() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    CaskToken::default().assert_not_guarded("burn");
+    CaskToken::default()
+        .burn(owner, token_ids)
+        .unwrap_or_revert()
+}
+
+#[no_mangle]
+fn owner_burn() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let reason = runtime::get_named_arg::<Option<String>>("reason");
+    CaskToken::default()
+        .owner_burn(owner, token_ids, reason)
+        .unwrap_or_revert()
+}
+
+#[no_mangle]
+fn approve() {
+    let spender = runtime::get_named_arg::<Key>("spender");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    CaskToken::default()
+        .approve(spender, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    if !CaskToken::default().is_kyc_proved(recipient) {
+        revert(ApiError::User(20));
+    }
+    CaskToken::default()
+        .transfer(recipient, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_from() {
+    let sender = runtime::get_named_arg::<Key>("sender");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    CaskToken::default().assert_not_guarded("transfer_from");
+    CaskToken::default()
+        .transfer_from(sender, recipient, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn force_transfer() {
+    let sender = runtime::get_named_arg::<Key>("sender");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let reason = runtime::get_named_arg::<String>("reason");
+    CaskToken::default().assert_not_guarded("force_transfer");
+    CaskToken::default()
+        .force_transfer(sender, recipient, token_ids, reason)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn set_user() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let user = runtime::get_named_arg::<Key>("user");
+    let expires = runtime::get_named_arg::<u64>("expires");
+    CaskToken::default()
+        .set_user(token_id, user, expires)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn user_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().user_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn lock_as_collateral() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let lender = runtime::get_named_arg::<Key>("lender");
+    CaskToken::default()
+        .lock_as_collateral(token_id, lender)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn release() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    CaskToken::default().release(token_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn liquidate() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    CaskToken::default()
+        .liquidate(token_id, recipient)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn clear_collateral_lock() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    CaskToken::default()
+        .clear_collateral_lock(token_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn collateral_lender() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().collateral_lender(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn ownership_history() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().ownership_history(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_recovery_delay() {
+    let delay = runtime::get_named_arg::<u64>("delay");
+    CaskToken::default().assert_not_guarded("set_recovery_delay");
+    CaskToken::default().set_recovery_delay(delay);
+}
+
+#[no_mangle]
+fn request_recovery() {
+    let old_owner = runtime::get_named_arg::<Key>("old_owner");
+    let new_owner = runtime::get_named_arg::<Key>("new_owner");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    CaskToken::default().assert_not_guarded("request_recovery");
+    let ret = CaskToken::default()
+        .request_recovery(old_owner, new_owner, token_ids)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn cancel_recovery() {
+    let request_id = runtime::get_named_arg::<u64>("request_id");
+    CaskToken::default()
+        .cancel_recovery(request_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn execute_recovery() {
+    let request_id = runtime::get_named_arg::<u64>("request_id");
+    CaskToken::default().assert_not_guarded("execute_recovery");
+    CaskToken::default()
+        .execute_recovery(request_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_jurisdictions() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_jurisdictions(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_jurisdictions() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let jurisdictions = runtime::get_named_arg::<Vec<String>>("jurisdictions");
+    CaskToken::default()
+        .set_token_jurisdictions(token_id, jurisdictions)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn post_attestation() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let warehouse_id = runtime::get_named_arg::<String>("warehouse_id");
+    let inspection_date = runtime::get_named_arg::<u64>("inspection_date");
+    let volume = runtime::get_named_arg::<u64>("volume");
+    let abv = runtime::get_named_arg::<u32>("abv");
+    let document_hash = runtime::get_named_arg::<String>("document_hash");
+    CaskToken::default()
+        .post_attestation(
+            token_id,
+            warehouse_id,
+            inspection_date,
+            volume,
+            abv,
+            document_hash,
+        )
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_attestations() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_attestations(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn latest_attestation() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().latest_attestation(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_attestation_stale() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().is_attestation_stale(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_attestation_max_age() {
+    let max_age = runtime::get_named_arg::<u64>("max_age");
+    CaskToken::default().set_attestation_max_age(max_age);
+}
+
+#[no_mangle]
+fn set_block_stale_transfers() {
+    let block = runtime::get_named_arg::<bool>("block");
+    CaskToken::default().set_block_stale_transfers(block);
+}
+
+#[no_mangle]
+fn token_regauges() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_regauges(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn latest_regauge() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().latest_regauge(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn add_regauge() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let date = runtime::get_named_arg::<u64>("date");
+    let bulk = runtime::get_named_arg::<u64>("bulk");
+    let abv = runtime::get_named_arg::<u32>("abv");
+    let lpa = runtime::get_named_arg::<u64>("lpa");
+    CaskToken::default()
+        .add_regauge(token_id, date, bulk, abv, lpa)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn correct_regauge() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let date = runtime::get_named_arg::<u64>("date");
+    let bulk = runtime::get_named_arg::<u64>("bulk");
+    let abv = runtime::get_named_arg::<u32>("abv");
+    let lpa = runtime::get_named_arg::<u64>("lpa");
+    CaskToken::default()
+        .correct_regauge(token_id, date, bulk, abv, lpa)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_documents() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_documents(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn add_token_document() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let doc_type = runtime::get_named_arg::<String>("doc_type");
+    let hash = runtime::get_named_arg::<String>("hash");
+    let uri = runtime::get_named_arg::<String>("uri");
+    let ret = CaskToken::default()
+        .add_token_document(token_id, doc_type, hash, uri)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn revoke_token_document() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let index = runtime::get_named_arg::<u32>("index");
+    CaskToken::default()
+        .revoke_token_document(token_id, index)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn replace_token_document() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let index = runtime::get_named_arg::<u32>("index");
+    let hash = runtime::get_named_arg::<String>("hash");
+    let uri = runtime::get_named_arg::<String>("uri");
+    let ret = CaskToken::default()
+        .replace_token_document(token_id, index, hash, uri)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn fill_timestamp() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().fill_timestamp(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_fill_timestamp() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let fill_timestamp = runtime::get_named_arg::<u64>("fill_timestamp");
+    CaskToken::default()
+        .set_fill_timestamp(token_id, fill_timestamp)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn cask_age() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().cask_age(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn matures_at() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let years = runtime::get_named_arg::<u32>("years");
+    let ret = CaskToken::default().matures_at(token_id, years);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_min_transfer_age() {
+    let min_age = runtime::get_named_arg::<u64>("min_age");
+    CaskToken::default().set_min_transfer_age(min_age);
+}
+
+#[no_mangle]
+fn token_insurance() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_insurance(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_insurance() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let policy_hash = runtime::get_named_arg::<String>("policy_hash");
+    let insurer = runtime::get_named_arg::<String>("insurer");
+    let coverage_value = runtime::get_named_arg::<U512>("coverage_value");
+    let expiry = runtime::get_named_arg::<u64>("expiry");
+    CaskToken::default()
+        .set_token_insurance(token_id, policy_hash, insurer, coverage_value, expiry)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn remove_token_insurance() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    CaskToken::default().remove_token_insurance(token_id);
+}
+
+#[no_mangle]
+fn set_require_insurance() {
+    let require = runtime::get_named_arg::<bool>("require");
+    CaskToken::default().set_require_insurance(require);
+}
+
+#[no_mangle]
+fn insurance_expiring_before() {
+    let timestamp = runtime::get_named_arg::<u64>("timestamp");
+    let offset = runtime::get_named_arg::<U256>("offset");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = CaskToken::default().insurance_expiring_before(timestamp, offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_valuations() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().token_valuations(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn latest_valuation() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().latest_valuation(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn owner_valuation() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let ret = CaskToken::default().owner_valuation(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn publish_valuation() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let value = runtime::get_named_arg::<U512>("value");
+    let timestamp = runtime::get_named_arg::<u64>("timestamp");
+    let source = runtime::get_named_arg::<String>("source");
+    CaskToken::default()
+        .publish_valuation(token_id, value, timestamp, source)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn settle_sale() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let seller = runtime::get_named_arg::<Key>("seller");
+    let buyer = runtime::get_named_arg::<Key>("buyer");
+    let amount = runtime::get_named_arg::<U512>("amount");
+    let purse = runtime::get_named_arg::<URef>("purse");
+    CaskToken::default()
+        .settle_sale(token_id, seller, buyer, amount, purse)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn withdraw() {
+    let amount = runtime::get_named_arg::<U512>("amount");
+    let purse = runtime::get_named_arg::<Option<URef>>("purse");
+    CaskToken::default()
+        .withdraw(amount, purse)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn set_storage_fee() {
+    let fee = runtime::get_named_arg::<U512>("fee");
+    CaskToken::default().assert_not_guarded("set_storage_fee");
+    CaskToken::default().set_storage_fee(fee);
+}
+
+#[no_mangle]
+fn set_storage_fee_grace_period() {
+    let grace_period = runtime::get_named_arg::<u64>("grace_period");
+    CaskToken::default().set_storage_fee_grace_period(grace_period);
+}
+
+#[no_mangle]
+fn set_block_unpaid_transfers() {
+    let block = runtime::get_named_arg::<bool>("block");
+    CaskToken::default().set_block_unpaid_transfers(block);
+}
+
+#[no_mangle]
+fn set_treasury() {
+    let treasury = runtime::get_named_arg::<Key>("treasury");
+    CaskToken::default().assert_not_guarded("set_treasury");
+    CaskToken::default().set_treasury(treasury);
+}
+
+#[no_mangle]
+fn storage_fees_owed() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = CaskToken::default().storage_fees_owed(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn pay_storage_fees() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let purse = runtime::get_named_arg::<URef>("purse");
+    let ret = CaskToken::default()
+        .pay_storage_fees(token_id, purse)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn balance_owed() {
+    let key = runtime::get_named_arg::<Key>("key");
+    let ret = CaskToken::default().balance_owed(key);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn grant_minter() {
+    let minter = runtime::get_named_arg::<Key>("minter");
+    CaskToken::default().assert_not_guarded("grant_minter");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().assert_not_denylisted(minter);
+    CaskToken::default().add_minter(minter);
+}
+
+#[no_mangle]
+fn revoke_minter() {
+    let minter = runtime::get_named_arg::<Key>("minter");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().revoke_minter(minter);
+}
+
+#[no_mangle]
+fn grant_admin() {
+    let admin = runtime::get_named_arg::<Key>("admin");
+    CaskToken::default().assert_not_guarded("grant_admin");
+    CaskToken::default().assert_not_denylisted(admin);
+    CaskToken::default().add_admin(admin);
+}
+
+#[no_mangle]
+fn revoke_admin() {
+    let admin = runtime::get_named_arg::<Key>("admin");
+    CaskToken::default().assert_not_guarded("revoke_admin");
+    CaskToken::default().disable_admin(admin);
+}
+
+#[no_mangle]
+fn grant_compliance() {
+    let compliance = runtime::get_named_arg::<Key>("compliance");
+    CaskToken::default().assert_not_guarded("grant_compliance");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().assert_not_denylisted(compliance);
+    CaskToken::default().add_compliance(compliance);
+}
+
+#[no_mangle]
+fn revoke_compliance() {
+    let compliance = runtime::get_named_arg::<Key>("compliance");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().revoke_compliance(compliance);
+}
+
+#[no_mangle]
+fn grant_custodian() {
+    let custodian = runtime::get_named_arg::<Key>("custodian");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().assert_not_denylisted(custodian);
+    CaskToken::default().add_custodian(custodian);
+}
+
+#[no_mangle]
+fn revoke_custodian() {
+    let custodian = runtime::get_named_arg::<Key>("custodian");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().revoke_custodian(custodian);
+}
+
+#[no_mangle]
+fn grant_oracle() {
+    let oracle = runtime::get_named_arg::<Key>("oracle");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().assert_not_denylisted(oracle);
+    CaskToken::default().add_oracle(oracle);
+}
+
+#[no_mangle]
+fn revoke_oracle() {
+    let oracle = runtime::get_named_arg::<Key>("oracle");
+    CaskToken::default().assert_caller_is_admin();
+    CaskToken::default().revoke_oracle(oracle);
+}
+
+#[no_mangle]
+fn add_to_denylist() {
+    let account = runtime::get_named_arg::<Key>("account");
+    CaskToken::default().add_to_denylist(account);
+}
+
+#[no_mangle]
+fn remove_from_denylist() {
+    let account = runtime::get_named_arg::<Key>("account");
+    CaskToken::default().remove_from_denylist(account);
+}
+
+#[no_mangle]
+fn is_denylisted() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let ret = CaskToken::default().is_denylisted(account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_quorum_threshold() {
+    let threshold = runtime::get_named_arg::<u32>("threshold");
+    CaskToken::default().assert_quorum_not_required();
+    QuorumControl::set_quorum_threshold(&mut CaskToken::default(), threshold).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn set_guarded_entry_point() {
+    let entry_point = runtime::get_named_arg::<String>("entry_point");
+    let guarded = runtime::get_named_arg::<bool>("guarded");
+    CaskToken::default().assert_quorum_not_required();
+    if !GUARDABLE_ENTRY_POINTS.contains(&entry_point.as_str()) {
+        revert(Error::WrongArguments);
+    }
+    CaskToken::default().set_guarded_entry_point(entry_point, guarded);
+}
+
+#[no_mangle]
+fn propose() {
+    let action = runtime::get_named_arg::<String>("action");
+    let args = runtime::get_named_arg::<Vec<u8>>("args");
+    let deadline = runtime::get_named_arg::<u64>("deadline");
+    let ret = CaskToken::default()
+        .propose(action, args, deadline)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn approve_proposal() {
+    let proposal_id = runtime::get_named_arg::<u64>("proposal_id");
+    CaskToken::default()
+        .approve_proposal(proposal_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    // Read arguments for the constructor call.
+    let name: String = runtime::get_named_arg("name");
+    let symbol: String = runtime::get_named_arg("symbol");
+    let meta: Meta = runtime::get_named_arg("meta");
+    let admin: Key = runtime::get_named_arg("admin");
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let kyc_package_hash: [u8; 32] = runtime::get_named_arg::<Key>(KYC_HASH)
+        .into_hash()
+        .unwrap_or_default();
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(KYC_HASH.into(), storage::new_uref(kyc_package_hash).into());
+
+    let (contract_hash, _) = storage::new_contract(
+        get_entry_points(),
+        Some(named_keys),
+        Some(String::from(&format!(
+            "{}_contract_package_hash",
+            contract_name
+        ))),
+        Some(format!("{}_package_access_token", contract_name)),
+    );
+
+    // Prepare constructor args
+    let constructor_args = runtime_args! {
+        "name" => name,
+        "symbol" => symbol,
+        "meta" => meta,
+        "admin" => admin
+    };
+
+    let package_hash = ContractPackageHash::new(
+        runtime::get_key(&format!("{}_contract_package_hash", contract_name))
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    // Add the constructor group to the package hash with a single URef.
+    let constructor_access: URef =
+        storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+
+    // Call the constructor entry point
+    let _: () = runtime::call_contract(contract_hash, "constructor", constructor_args);
+
+    // Remove all URefs from the constructor group, so no one can call it for the second time.
+    let mut urefs = BTreeSet::new();
+    urefs.insert(constructor_access);
+    storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+        .unwrap_or_revert();
+
+    // Store contract in the account's named keys.
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+    runtime::put_key(
+        &format!("{}_package_hash_wrapped", contract_name),
+        storage::new_uref(package_hash).into(),
+    );
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("name", String::cl_type()),
+            Parameter::new("symbol", String::cl_type()),
+            Parameter::new("meta", Meta::cl_type()),
+            Parameter::new("admin", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "name",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "symbol",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta",
+        vec![],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_token_by_index",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("index", U256::cl_type()),
+        ],
+        CLType::Option(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "tokens_of",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("offset", U256::cl_type()),
+            Parameter::new("limit", CLType::U32),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_by_index",
+        vec![Parameter::new("index", U256::cl_type())],
+        CLType::Option(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "all_tokens",
+        vec![
+            Parameter::new("offset", U256::cl_type()),
+            Parameter::new("limit", CLType::U32),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_meta",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_commission",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <Option<(Commission, String)>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_metas",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        <Vec<Option<Meta>>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_commissions",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        <Vec<Option<(Commission, String)>>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_info",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <Option<TokenInfo>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "default_commission",
+        vec![],
+        Commission::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_default_commission",
+        vec![Parameter::new("commission", Commission::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "clear_token_commission",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta", Meta::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_token_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta_key", String::cl_type()),
+            Parameter::new("token_meta_value", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_token_commission",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("property", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("mode", String::cl_type()),
+            Parameter::new("value", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_id_keys",
+        vec![],
+        CLType::List(Box::new(String::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_id_keys",
+        vec![Parameter::new(
+            "keys",
+            CLType::List(Box::new(String::cl_type())),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_unique_meta_key",
+        vec![Parameter::new("key", String::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_by_physical_id",
+        vec![Parameter::new("physical_id", String::cl_type())],
+        CLType::Option(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new(
+                "token_ids",
+                CLType::Option(Box::new(CLType::List(Box::new(TokenId::cl_type())))),
+            ),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new(
+                "token_commissions",
+                CLType::List(Box::new(Commission::cl_type())),
+            ),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_copies",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new(
+                "token_ids",
+                CLType::Option(Box::new(CLType::List(Box::new(TokenId::cl_type())))),
+            ),
+            Parameter::new("token_meta", Meta::cl_type()),
+            Parameter::new("count", CLType::U32),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_burn",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("reason", CLType::Option(Box::new(String::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve",
+        vec![
+            Parameter::new("spender", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_from",
+        vec![
+            Parameter::new("sender", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "force_transfer",
+        vec![
+            Parameter::new("sender", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("reason", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "ownership_history",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(OwnershipRecord::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_recovery_delay",
+        vec![Parameter::new("delay", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "request_recovery",
+        vec![
+            Parameter::new("old_owner", Key::cl_type()),
+            Parameter::new("new_owner", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_recovery",
+        vec![Parameter::new("request_id", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute_recovery",
+        vec![Parameter::new("request_id", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_jurisdictions",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(String::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_jurisdictions",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("jurisdictions", CLType::List(Box::new(String::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "settle_sale",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("seller", Key::cl_type()),
+            Parameter::new("buyer", Key::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+            Parameter::new("purse", URef::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw",
+        vec![
+            Parameter::new("amount", U512::cl_type()),
+            Parameter::new("purse", CLType::Option(Box::new(URef::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_owed",
+        vec![Parameter::new("key", Key::cl_type())],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_minter",
+        vec![Parameter::new("minter", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_minter",
+        vec![Parameter::new("minter", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_admin",
+        vec![Parameter::new("admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_admin",
+        vec![Parameter::new("admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_compliance",
+        vec![Parameter::new("compliance", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_compliance",
+        vec![Parameter::new("compliance", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "add_to_denylist",
+        vec![Parameter::new("account", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "remove_from_denylist",
+        vec![Parameter::new("account", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_denylisted",
+        vec![Parameter::new("account", Key::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_quorum_threshold",
+        vec![Parameter::new("threshold", CLType::U32)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_guarded_entry_point",
+        vec![
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("guarded", CLType::Bool),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "propose",
+        vec![
+            Parameter::new("action", String::cl_type()),
+            Parameter::new("args", CLType::List(Box::new(CLType::U8))),
+            Parameter::new("deadline", CLType::U64),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_proposal",
+        vec![Parameter::new("proposal_id", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_custodian",
+        vec![Parameter::new("custodian", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_custodian",
+        vec![Parameter::new("custodian", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "post_attestation",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("warehouse_id", String::cl_type()),
+            Parameter::new("inspection_date", CLType::U64),
+            Parameter::new("volume", CLType::U64),
+            Parameter::new("abv", CLType::U32),
+            Parameter::new("document_hash", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_attestations",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(Attestation::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "latest_attestation",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(Attestation::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_attestation_stale",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_attestation_max_age",
+        vec![Parameter::new("max_age", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_block_stale_transfers",
+        vec![Parameter::new("block", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_documents",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(TokenDocument::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "add_token_document",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("doc_type", String::cl_type()),
+            Parameter::new("hash", String::cl_type()),
+            Parameter::new("uri", String::cl_type()),
+        ],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_token_document",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("index", CLType::U32),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "replace_token_document",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("index", CLType::U32),
+            Parameter::new("hash", String::cl_type()),
+            Parameter::new("uri", String::cl_type()),
+        ],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "split_into_bottles",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("count", CLType::U32),
+            Parameter::new("bottle_meta", Meta::cl_type()),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "children_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
-        "constructor",
+        "parent_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "merge_tokens",
         vec![
-            Parameter::new("name", String::cl_type()),
-            Parameter::new("symbol", String::cl_type()),
-            Parameter::new("meta", Meta::cl_type()),
-            Parameter::new("admin", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("new_meta", Meta::cl_type()),
+            Parameter::new("commission_policy", String::cl_type()),
+        ],
+        TokenId::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "lineage_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "fill_timestamp",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_fill_timestamp",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("fill_timestamp", CLType::U64),
         ],
         <()>::cl_type(),
-        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "name",
-        vec![],
-        String::cl_type(),
+        "cask_age",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::U64)),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "symbol",
-        vec![],
-        String::cl_type(),
+        "matures_at",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("years", CLType::U32),
+        ],
+        CLType::Option(Box::new(CLType::U64)),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "meta",
-        vec![],
-        Meta::cl_type(),
+        "set_min_transfer_age",
+        vec![Parameter::new("min_age", CLType::U64)],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "total_supply",
-        vec![],
-        U256::cl_type(),
+        "token_regauges",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(Regauge::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "balance_of",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
+        "latest_regauge",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(Regauge::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "owner_of",
+        "add_regauge",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("date", CLType::U64),
+            Parameter::new("bulk", CLType::U64),
+            Parameter::new("abv", CLType::U32),
+            Parameter::new("lpa", CLType::U64),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "correct_regauge",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("date", CLType::U64),
+            Parameter::new("bulk", CLType::U64),
+            Parameter::new("abv", CLType::U32),
+            Parameter::new("lpa", CLType::U64),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_storage_fee",
+        vec![Parameter::new("fee", U512::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_storage_fee_grace_period",
+        vec![Parameter::new("grace_period", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_block_unpaid_transfers",
+        vec![Parameter::new("block", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_treasury",
+        vec![Parameter::new("treasury", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "storage_fees_owed",
         vec![Parameter::new("token_id", TokenId::cl_type())],
-        CLType::Option(Box::new(CLType::Key)),
+        U512::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "get_token_by_index",
+        "pay_storage_fees",
         vec![
-            Parameter::new("owner", Key::cl_type()),
-            Parameter::new("index", U256::cl_type()),
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("purse", URef::cl_type()),
         ],
-        CLType::Option(Box::new(TokenId::cl_type())),
+        U512::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "token_meta",
+        "token_insurance",
         vec![Parameter::new("token_id", TokenId::cl_type())],
-        Meta::cl_type(),
+        CLType::Option(Box::new(InsuranceRecord::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "token_commission",
+        "set_token_insurance",
         vec![
             Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("property", String::cl_type()),
+            Parameter::new("policy_hash", String::cl_type()),
+            Parameter::new("insurer", String::cl_type()),
+            Parameter::new("coverage_value", U512::cl_type()),
+            Parameter::new("expiry", CLType::U64),
         ],
-        Commission::cl_type(),
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "set_token_meta",
+        "remove_token_insurance",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_require_insurance",
+        vec![Parameter::new("require", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "insurance_expiring_before",
+        vec![
+            Parameter::new("timestamp", CLType::U64),
+            Parameter::new("offset", U256::cl_type()),
+            Parameter::new("limit", CLType::U32),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_oracle",
+        vec![Parameter::new("oracle", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_oracle",
+        vec![Parameter::new("oracle", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_valuations",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::List(Box::new(Valuation::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "latest_valuation",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(Valuation::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_valuation",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "publish_valuation",
         vec![
             Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("token_meta", Meta::cl_type()),
+            Parameter::new("value", U512::cl_type()),
+            Parameter::new("timestamp", CLType::U64),
+            Parameter::new("source", String::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "update_token_meta",
+        "lock_as_collateral",
         vec![
             Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("token_meta_key", String::cl_type()),
-            Parameter::new("token_meta_value", String::cl_type()),
+            Parameter::new("lender", Key::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "update_token_commission",
+        "clear_collateral_lock",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "release",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "liquidate",
         vec![
             Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("property", String::cl_type()),
-            Parameter::new("account", Key::cl_type()),
-            Parameter::new("mode", String::cl_type()),
-            Parameter::new("value", String::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "mint",
+        "collateral_lender",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_user",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("user", Key::cl_type()),
+            Parameter::new("expires", CLType::U64),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "user_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_soulbound",
         vec![
             Parameter::new("recipient", Key::cl_type()),
             Parameter::new(
@@ -638,78 +4381,123 @@ fn get_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "mint_copies",
+        "is_soulbound",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_collection",
         vec![
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new(
-                "token_ids",
-                CLType::Option(Box::new(CLType::List(Box::new(TokenId::cl_type())))),
-            ),
-            Parameter::new("token_meta", Meta::cl_type()),
-            Parameter::new("count", CLType::U32),
+            Parameter::new("collection_id", String::cl_type()),
+            Parameter::new("name", String::cl_type()),
+            Parameter::new("meta", Meta::cl_type()),
+            Parameter::new("commission", Commission::cl_type()),
+            Parameter::new("cap", CLType::Option(Box::new(U256::cl_type()))),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "burn",
+        "update_collection",
         vec![
-            Parameter::new("owner", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("collection_id", String::cl_type()),
+            Parameter::new("name", String::cl_type()),
+            Parameter::new("meta", Meta::cl_type()),
+            Parameter::new("commission", Commission::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "transfer",
+        "grant_collection_minter",
         vec![
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("collection_id", String::cl_type()),
+            Parameter::new("minter", Key::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "transfer_from",
+        "revoke_collection_minter",
         vec![
-            Parameter::new("sender", Key::cl_type()),
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("collection_id", String::cl_type()),
+            Parameter::new("minter", Key::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "grant_minter",
-        vec![Parameter::new("minter", Key::cl_type())],
+        "mint_to_collection",
+        vec![
+            Parameter::new("collection_id", String::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new(
+                "token_ids",
+                CLType::Option(Box::new(CLType::List(Box::new(TokenId::cl_type())))),
+            ),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new(
+                "token_commissions",
+                CLType::List(Box::new(Commission::cl_type())),
+            ),
+        ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "revoke_minter",
-        vec![Parameter::new("minter", Key::cl_type())],
-        <()>::cl_type(),
+        "collection",
+        vec![Parameter::new("collection_id", String::cl_type())],
+        CLType::Option(Box::new(Collection::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "grant_admin",
-        vec![Parameter::new("admin", Key::cl_type())],
-        <()>::cl_type(),
+        "collection_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(String::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "revoke_admin",
-        vec![Parameter::new("admin", Key::cl_type())],
+        "collection_supply",
+        vec![Parameter::new("collection_id", String::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_supply_cap",
+        vec![
+            Parameter::new("collection_id", CLType::Option(Box::new(String::cl_type()))),
+            Parameter::new("cap", U256::cl_type()),
+        ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_supply",
+        vec![],
+        CLType::Option(Box::new(U256::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "remaining_supply",
+        vec![Parameter::new(
+            "collection_id",
+            CLType::Option(Box::new(String::cl_type())),
+        )],
+        CLType::Option(Box::new(U256::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
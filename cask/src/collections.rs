@@ -0,0 +1,157 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+use cep47::{contract_utils::Dict, Meta};
+
+use crate::Commission;
+
+const COLLECTIONS_DICT: &str = "collections";
+const TOKEN_COLLECTIONS_DICT: &str = "token_collections";
+const COLLECTION_SUPPLIES_DICT: &str = "collection_supplies";
+
+/// Collection every token minted outside `mint_to_collection` belongs to.
+pub const DEFAULT_COLLECTION: &str = "default";
+
+/// A series of tokens, such as a single distillery drop. Tokens minted into it without a
+/// commission fall back to its `commission`, and its `minters` may mint into it alongside
+/// the contract-wide minters.
+pub struct Collection {
+    pub name: String,
+    pub meta: Meta,
+    pub commission: Commission,
+    pub cap: Option<U256>,
+    pub minters: Vec<Key>,
+}
+
+impl CLTyped for Collection {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Collection {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.name.to_bytes()?);
+        result.extend(self.meta.to_bytes()?);
+        result.extend(self.commission.to_bytes()?);
+        result.extend(self.cap.to_bytes()?);
+        result.extend(self.minters.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length()
+            + self.meta.serialized_length()
+            + self.commission.serialized_length()
+            + self.cap.serialized_length()
+            + self.minters.serialized_length()
+    }
+}
+
+impl FromBytes for Collection {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, bytes) = String::from_bytes(bytes)?;
+        let (meta, bytes) = Meta::from_bytes(bytes)?;
+        let (commission, bytes) = Commission::from_bytes(bytes)?;
+        let (cap, bytes) = Option::<U256>::from_bytes(bytes)?;
+        let (minters, bytes) = Vec::<Key>::from_bytes(bytes)?;
+        Ok((
+            Collection {
+                name,
+                meta,
+                commission,
+                cap,
+                minters,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct Collections {
+    dict: Dict,
+}
+
+impl Collections {
+    pub fn instance() -> Collections {
+        Collections {
+            dict: Dict::instance(COLLECTIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(COLLECTIONS_DICT)
+    }
+
+    pub fn get(&self, collection_id: &str) -> Option<Collection> {
+        self.dict.get(collection_id)
+    }
+
+    pub fn set(&self, collection_id: &str, collection: Collection) {
+        self.dict.set(collection_id, collection);
+    }
+}
+
+pub struct TokenCollections {
+    dict: Dict,
+}
+
+impl TokenCollections {
+    pub fn instance() -> TokenCollections {
+        TokenCollections {
+            dict: Dict::instance(TOKEN_COLLECTIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_COLLECTIONS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<String> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, collection_id: &str) {
+        self.dict.set(token_id, String::from(collection_id));
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<String>(token_id);
+    }
+}
+
+/// Number of live tokens in each collection; burns free up room under its cap.
+pub struct CollectionSupplies {
+    dict: Dict,
+}
+
+impl CollectionSupplies {
+    pub fn instance() -> CollectionSupplies {
+        CollectionSupplies {
+            dict: Dict::instance(COLLECTION_SUPPLIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(COLLECTION_SUPPLIES_DICT)
+    }
+
+    pub fn get(&self, collection_id: &str) -> U256 {
+        self.dict.get(collection_id).unwrap_or_default()
+    }
+
+    pub fn add(&self, collection_id: &str, amount: U256) {
+        self.dict
+            .set(collection_id, self.get(collection_id) + amount);
+    }
+
+    pub fn sub(&self, collection_id: &str, amount: U256) {
+        self.dict.set(
+            collection_id,
+            self.get(collection_id).saturating_sub(amount),
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const COMPLIANCE_DICT: &str = "compliance_officers";
+pub trait ComplianceControl<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        ComplianceOfficers::init();
+    }
+
+    fn revoke_compliance(&mut self, address: Key) {
+        ComplianceOfficers::instance().revoke_compliance(&address);
+    }
+
+    fn add_compliance(&mut self, address: Key) {
+        ComplianceOfficers::instance().add_compliance(&address);
+    }
+
+    fn is_compliance(&self) -> bool {
+        let caller = self.get_caller();
+        ComplianceOfficers::instance().is_compliance(&caller)
+    }
+}
+
+struct ComplianceOfficers {
+    dict: Dict,
+}
+
+impl ComplianceOfficers {
+    pub fn instance() -> ComplianceOfficers {
+        ComplianceOfficers {
+            dict: Dict::instance(COMPLIANCE_DICT),
+        }
+    }
+    pub fn init() {
+        storage::new_dictionary(COMPLIANCE_DICT).unwrap_or_revert();
+    }
+
+    pub fn is_compliance(&self, key: &Key) -> bool {
+        self.dict.get_by_key::<()>(key).is_some()
+    }
+
+    pub fn add_compliance(&self, key: &Key) {
+        self.dict.set_by_key(key, ());
+    }
+
+    pub fn revoke_compliance(&self, key: &Key) {
+        self.dict.remove_by_key::<()>(key);
+    }
+}
@@ -0,0 +1,50 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const CUSTODIANS_DICT: &str = "custodians";
+pub trait CustodianControl<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Custodians::init();
+    }
+
+    fn revoke_custodian(&mut self, address: Key) {
+        Custodians::instance().revoke_custodian(&address);
+    }
+
+    fn add_custodian(&mut self, address: Key) {
+        Custodians::instance().add_custodian(&address);
+    }
+
+    fn is_custodian(&self) -> bool {
+        let caller = self.get_caller();
+        Custodians::instance().is_custodian(&caller)
+    }
+}
+
+struct Custodians {
+    dict: Dict,
+}
+
+impl Custodians {
+    pub fn instance() -> Custodians {
+        Custodians {
+            dict: Dict::instance(CUSTODIANS_DICT),
+        }
+    }
+    pub fn init() {
+        storage::new_dictionary(CUSTODIANS_DICT).unwrap_or_revert();
+    }
+
+    pub fn is_custodian(&self, key: &Key) -> bool {
+        self.dict.get_by_key::<()>(key).is_some()
+    }
+
+    pub fn add_custodian(&self, key: &Key) {
+        self.dict.set_by_key(key, ());
+    }
+
+    pub fn revoke_custodian(&self, key: &Key) {
+        self.dict.remove_by_key::<()>(key);
+    }
+}
@@ -1,7 +1,34 @@
 use crate::Commission;
-use cep47::contract_utils::Dict;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, Key, U256, U512};
+use cep47::{
+    contract_utils::{key_and_value_to_str, Dict},
+    Error, Meta, TokenId,
+};
 
 const COMMISSIONS_DICT: &str = "commissions";
+const BALANCES_OWED_DICT: &str = "balances_owed";
+const JURISDICTIONS_DICT: &str = "token_jurisdictions";
+const DENYLIST_DICT: &str = "denylist";
+const ALLOWANCES_DICT: &str = "allowances";
+const ALL_TOKENS_DICT: &str = "all_tokens";
+const ALL_TOKENS_INDEX_DICT: &str = "all_tokens_index";
+const PHYSICAL_IDS_DICT: &str = "physical_ids";
+const TOKEN_STATUSES_DICT: &str = "token_statuses";
+const TOKEN_CHILDREN_DICT: &str = "token_children";
+const TOKEN_PARENTS_DICT: &str = "token_parents";
+const TOKEN_LINEAGE_DICT: &str = "token_lineage";
+const FILL_TIMESTAMPS_DICT: &str = "fill_timestamps";
+const FEES_PAID_UNTIL_DICT: &str = "fees_paid_until";
+const FEE_INDEXES_PAID_DICT: &str = "fee_indexes_paid";
+const FEES_CARRIED_DICT: &str = "fees_carried";
+const COLLATERAL_LOCKS_DICT: &str = "collateral_locks";
+const TOKEN_USERS_DICT: &str = "token_users";
+const SOULBOUND_TOKENS_DICT: &str = "soulbound_tokens";
 
 pub struct Commissions {
     dict: Dict,
@@ -30,3 +57,528 @@ impl Commissions {
         self.dict.remove::<Commission>(key);
     }
 }
+
+pub struct BalancesOwed {
+    dict: Dict,
+}
+
+impl BalancesOwed {
+    pub fn instance() -> BalancesOwed {
+        BalancesOwed {
+            dict: Dict::instance(BALANCES_OWED_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(BALANCES_OWED_DICT)
+    }
+
+    pub fn get(&self, key: &Key) -> U512 {
+        self.dict.get_by_key(key).unwrap_or_default()
+    }
+
+    pub fn set(&self, key: &Key, value: U512) {
+        self.dict.set_by_key(key, value);
+    }
+}
+
+pub struct Jurisdictions {
+    dict: Dict,
+}
+
+impl Jurisdictions {
+    pub fn instance() -> Jurisdictions {
+        Jurisdictions {
+            dict: Dict::instance(JURISDICTIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(JURISDICTIONS_DICT)
+    }
+
+    pub fn get(&self, key: &str) -> Vec<String> {
+        self.dict.get(key).unwrap_or_default()
+    }
+
+    pub fn set(&self, key: &str, value: Vec<String>) {
+        self.dict.set(key, value);
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.dict.remove::<Vec<String>>(key);
+    }
+}
+
+pub struct Denylist {
+    dict: Dict,
+}
+
+impl Denylist {
+    pub fn instance() -> Denylist {
+        Denylist {
+            dict: Dict::instance(DENYLIST_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(DENYLIST_DICT)
+    }
+
+    pub fn is_denied(&self, key: &Key) -> bool {
+        self.dict.get_by_key::<()>(key).is_some()
+    }
+
+    pub fn deny(&self, key: &Key) {
+        self.dict.set_by_key(key, ());
+    }
+
+    pub fn allow(&self, key: &Key) {
+        self.dict.remove_by_key::<()>(key);
+    }
+}
+
+/// Index of every existing token, kept dense by moving the last token into burned slots.
+pub struct AllTokens {
+    tokens_dict: Dict,
+    indexes_dict: Dict,
+}
+
+impl AllTokens {
+    pub fn instance() -> AllTokens {
+        AllTokens {
+            tokens_dict: Dict::instance(ALL_TOKENS_DICT),
+            indexes_dict: Dict::instance(ALL_TOKENS_INDEX_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ALL_TOKENS_DICT);
+        Dict::init(ALL_TOKENS_INDEX_DICT);
+    }
+
+    pub fn get(&self, index: U256) -> Option<TokenId> {
+        self.tokens_dict.get(&index.to_string())
+    }
+
+    pub fn add(&self, index: U256, token_id: &TokenId) {
+        self.tokens_dict.set(&index.to_string(), token_id.clone());
+        self.indexes_dict.set(token_id, index);
+    }
+
+    pub fn remove(&self, token_id: &TokenId, last_index: U256) {
+        let index: U256 = self.indexes_dict.get(token_id).unwrap_or_revert();
+        if index != last_index {
+            let last_token_id: TokenId = self.get(last_index).unwrap_or_revert();
+            self.add(index, &last_token_id);
+        }
+        self.tokens_dict.remove::<TokenId>(&last_index.to_string());
+        self.indexes_dict.remove::<U256>(token_id);
+    }
+}
+
+/// Reverse lookup from a physical cask identifier to its token. Identifiers are hashed,
+/// so barcodes of any length fit the dictionary key limit.
+pub struct PhysicalIds {
+    dict: Dict,
+}
+
+impl PhysicalIds {
+    pub fn instance() -> PhysicalIds {
+        PhysicalIds {
+            dict: Dict::instance(PHYSICAL_IDS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(PHYSICAL_IDS_DICT)
+    }
+
+    pub fn get(&self, physical_id: &str) -> Option<TokenId> {
+        self.dict.get(&physical_id_to_str(physical_id))
+    }
+
+    pub fn set(&self, physical_id: &str, token_id: &TokenId) {
+        self.dict
+            .set(&physical_id_to_str(physical_id), token_id.clone());
+    }
+
+    pub fn remove(&self, physical_id: &str) {
+        self.dict
+            .remove::<TokenId>(&physical_id_to_str(physical_id));
+    }
+}
+
+/// Lifecycle stage of tokens that are no longer active. Active tokens have no entry.
+pub struct TokenStatuses {
+    dict: Dict,
+}
+
+impl TokenStatuses {
+    pub fn instance() -> TokenStatuses {
+        TokenStatuses {
+            dict: Dict::instance(TOKEN_STATUSES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_STATUSES_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<String> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, status: &str) {
+        self.dict.set(token_id, status.to_string());
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<String>(token_id);
+    }
+}
+
+pub struct TokenChildren {
+    dict: Dict,
+}
+
+impl TokenChildren {
+    pub fn instance() -> TokenChildren {
+        TokenChildren {
+            dict: Dict::instance(TOKEN_CHILDREN_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_CHILDREN_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Vec<TokenId> {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &str, children: Vec<TokenId>) {
+        self.dict.set(token_id, children);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Vec<TokenId>>(token_id);
+    }
+}
+
+pub struct TokenParents {
+    dict: Dict,
+}
+
+impl TokenParents {
+    pub fn instance() -> TokenParents {
+        TokenParents {
+            dict: Dict::instance(TOKEN_PARENTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_PARENTS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<TokenId> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, parent: &TokenId) {
+        self.dict.set(token_id, parent.clone());
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<TokenId>(token_id);
+    }
+}
+
+/// Casks vatted into a merged token.
+pub struct TokenLineage {
+    dict: Dict,
+}
+
+impl TokenLineage {
+    pub fn instance() -> TokenLineage {
+        TokenLineage {
+            dict: Dict::instance(TOKEN_LINEAGE_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_LINEAGE_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Vec<TokenId> {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &str, parents: Vec<TokenId>) {
+        self.dict.set(token_id, parents);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Vec<TokenId>>(token_id);
+    }
+}
+
+/// When each cask was filled, in milliseconds of block time.
+pub struct FillTimestamps {
+    dict: Dict,
+}
+
+impl FillTimestamps {
+    pub fn instance() -> FillTimestamps {
+        FillTimestamps {
+            dict: Dict::instance(FILL_TIMESTAMPS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(FILL_TIMESTAMPS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<u64> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, fill_timestamp: u64) {
+        self.dict.set(token_id, fill_timestamp);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<u64>(token_id);
+    }
+}
+
+/// Block time up to which each token's storage fees are settled.
+pub struct FeesPaidUntil {
+    dict: Dict,
+}
+
+impl FeesPaidUntil {
+    pub fn instance() -> FeesPaidUntil {
+        FeesPaidUntil {
+            dict: Dict::instance(FEES_PAID_UNTIL_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(FEES_PAID_UNTIL_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> u64 {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &str, paid_until: u64) {
+        self.dict.set(token_id, paid_until);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<u64>(token_id);
+    }
+}
+
+/// Storage fee index each token's fees are settled up to. Tokens without an entry, such
+/// as bottles and retired casks, don't accrue fees.
+pub struct FeeIndexesPaid {
+    dict: Dict,
+}
+
+impl FeeIndexesPaid {
+    pub fn instance() -> FeeIndexesPaid {
+        FeeIndexesPaid {
+            dict: Dict::instance(FEE_INDEXES_PAID_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(FEE_INDEXES_PAID_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<U512> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, index: U512) {
+        self.dict.set(token_id, index);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<U512>(token_id);
+    }
+}
+
+/// Storage fees a token owes on top of those it accrues, fixed when a cask is retired or
+/// carried over from the casks merged into it.
+pub struct FeesCarried {
+    dict: Dict,
+}
+
+impl FeesCarried {
+    pub fn instance() -> FeesCarried {
+        FeesCarried {
+            dict: Dict::instance(FEES_CARRIED_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(FEES_CARRIED_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> U512 {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &str, amount: U512) {
+        self.dict.set(token_id, amount);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<U512>(token_id);
+    }
+}
+
+/// Lender holding each token pledged as loan collateral.
+pub struct CollateralLocks {
+    dict: Dict,
+}
+
+impl CollateralLocks {
+    pub fn instance() -> CollateralLocks {
+        CollateralLocks {
+            dict: Dict::instance(COLLATERAL_LOCKS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(COLLATERAL_LOCKS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<Key> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, lender: Key) {
+        self.dict.set(token_id, lender);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Key>(token_id);
+    }
+}
+
+/// Account granted usage rights to each token, with the block time the rights end at.
+pub struct TokenUsers {
+    dict: Dict,
+}
+
+impl TokenUsers {
+    pub fn instance() -> TokenUsers {
+        TokenUsers {
+            dict: Dict::instance(TOKEN_USERS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_USERS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<(Key, u64)> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, user: Key, expires: u64) {
+        self.dict.set(token_id, (user, expires));
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<(Key, u64)>(token_id);
+    }
+}
+
+/// Tokens minted as non-transferable, such as visit certificates and collector badges.
+pub struct SoulboundTokens {
+    dict: Dict,
+}
+
+impl SoulboundTokens {
+    pub fn instance() -> SoulboundTokens {
+        SoulboundTokens {
+            dict: Dict::instance(SOULBOUND_TOKENS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(SOULBOUND_TOKENS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> bool {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &str) {
+        self.dict.set(token_id, true);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<bool>(token_id);
+    }
+}
+
+fn physical_id_to_str(physical_id: &str) -> String {
+    hex::encode(runtime::blake2b(physical_id))
+}
+
+/// Approvals granted through cep47's `approve`, which owns and initializes the dictionary.
+pub struct Allowances {
+    dict: Dict,
+}
+
+impl Allowances {
+    pub fn instance() -> Allowances {
+        Allowances {
+            dict: Dict::instance(ALLOWANCES_DICT),
+        }
+    }
+
+    pub fn get(&self, owner: &Key, token_id: &TokenId) -> Option<Key> {
+        self.dict.get(&key_and_value_to_str(owner, token_id))
+    }
+
+    pub fn remove(&self, owner: &Key, token_id: &TokenId) {
+        self.dict
+            .remove::<Key>(&key_and_value_to_str(owner, token_id));
+    }
+}
+
+/// Parses a commission account stored with `Key::to_string()` back into a `Key`.
+pub fn commission_account(value: &str) -> Option<Key> {
+    let formatted = if let Some(hash) = value
+        .strip_prefix("Key::Account(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        format!("account-hash-{}", hash)
+    } else if let Some(hash) = value
+        .strip_prefix("Key::Hash(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        format!("hash-{}", hash)
+    } else {
+        value.into()
+    };
+    Key::from_formatted_str(&formatted).ok()
+}
+
+/// Derives a token id from the values of `keys` in `meta`, in the given order.
+/// Fails with `WrongArguments` when the metadata lacks one of the keys.
+pub fn derive_token_id(meta: &Meta, keys: &[String]) -> Result<TokenId, Error> {
+    let mut values = Vec::new();
+    for key in keys {
+        values.push(meta.get(key).ok_or(Error::WrongArguments)?.clone());
+    }
+    let bytes = values.to_bytes().unwrap_or_revert();
+    Ok(hex::encode(runtime::blake2b(bytes)))
+}
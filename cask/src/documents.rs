@@ -0,0 +1,108 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::contract_utils::Dict;
+
+const TOKEN_DOCUMENTS_DICT: &str = "token_documents";
+
+/// Commitment to an off-chain document. `hash` is the hex encoded blake2b-256 hash of the
+/// document, and `replaced_by` points at the index of the record superseding a revoked one.
+pub struct TokenDocument {
+    pub doc_type: String,
+    pub hash: String,
+    pub uri: String,
+    pub added_by: Key,
+    pub added_at: u64,
+    pub revoked: bool,
+    pub replaced_by: Option<u32>,
+}
+
+impl CLTyped for TokenDocument {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TokenDocument {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.doc_type.to_bytes()?);
+        result.extend(self.hash.to_bytes()?);
+        result.extend(self.uri.to_bytes()?);
+        result.extend(self.added_by.to_bytes()?);
+        result.extend(self.added_at.to_bytes()?);
+        result.extend(self.revoked.to_bytes()?);
+        result.extend(self.replaced_by.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.doc_type.serialized_length()
+            + self.hash.serialized_length()
+            + self.uri.serialized_length()
+            + self.added_by.serialized_length()
+            + self.added_at.serialized_length()
+            + self.revoked.serialized_length()
+            + self.replaced_by.serialized_length()
+    }
+}
+
+impl FromBytes for TokenDocument {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (doc_type, bytes) = String::from_bytes(bytes)?;
+        let (hash, bytes) = String::from_bytes(bytes)?;
+        let (uri, bytes) = String::from_bytes(bytes)?;
+        let (added_by, bytes) = Key::from_bytes(bytes)?;
+        let (added_at, bytes) = u64::from_bytes(bytes)?;
+        let (revoked, bytes) = bool::from_bytes(bytes)?;
+        let (replaced_by, bytes) = Option::<u32>::from_bytes(bytes)?;
+        Ok((
+            TokenDocument {
+                doc_type,
+                hash,
+                uri,
+                added_by,
+                added_at,
+                revoked,
+                replaced_by,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct TokenDocuments {
+    dict: Dict,
+}
+
+impl TokenDocuments {
+    pub fn instance() -> TokenDocuments {
+        TokenDocuments {
+            dict: Dict::instance(TOKEN_DOCUMENTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_DOCUMENTS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Vec<TokenDocument> {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &str, documents: Vec<TokenDocument>) {
+        self.dict.set(token_id, documents);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Vec<TokenDocument>>(token_id);
+    }
+}
+
+/// Whether `hash` is a blake2b-256 hash hex encoded the way `runtime::blake2b` results are
+/// turned into dictionary keys.
+pub fn is_blake2b_hex(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
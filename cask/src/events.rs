@@ -0,0 +1,82 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{system::CallStackElement, ContractPackageHash, Key, URef};
+use cep47::TokenId;
+
+pub enum CaskEvent {
+    ForceTransfer {
+        sender: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+        reason: String,
+    },
+    Burn {
+        owner: Key,
+        token_ids: Vec<TokenId>,
+        reason: String,
+    },
+}
+
+pub fn emit(event: &CaskEvent) {
+    let mut events = Vec::new();
+    let package = contract_package_hash();
+    match event {
+        CaskEvent::ForceTransfer {
+            sender,
+            recipient,
+            token_ids,
+            reason,
+        } => {
+            for token_id in token_ids {
+                let mut param = event_param(&package, "cask_force_transfer");
+                param.insert("sender".to_string(), sender.to_string());
+                param.insert("recipient".to_string(), recipient.to_string());
+                param.insert("token_id".to_string(), token_id.to_string());
+                param.insert("reason".to_string(), reason.clone());
+                events.push(param);
+            }
+        }
+        CaskEvent::Burn {
+            owner,
+            token_ids,
+            reason,
+        } => {
+            for token_id in token_ids {
+                let mut param = event_param(&package, "cask_burn");
+                param.insert("owner".to_string(), owner.to_string());
+                param.insert("token_id".to_string(), token_id.to_string());
+                param.insert("reason".to_string(), reason.clone());
+                events.push(param);
+            }
+        }
+    };
+    for param in events {
+        let _: URef = storage::new_uref(param);
+    }
+}
+
+fn event_param(package: &ContractPackageHash, event_type: &str) -> BTreeMap<String, String> {
+    let mut param = BTreeMap::new();
+    param.insert("contract_package_hash".to_string(), package.to_string());
+    param.insert("event_type".to_string(), event_type.to_string());
+    param
+}
+
+fn contract_package_hash() -> ContractPackageHash {
+    let call_stack = runtime::get_call_stack();
+    let package_hash = match call_stack.last().unwrap_or_revert() {
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Some(*contract_package_hash),
+        _ => None,
+    };
+    package_hash.unwrap_or_revert()
+}
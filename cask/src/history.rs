@@ -0,0 +1,130 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::contract_utils::Dict;
+
+const OWNERSHIP_HISTORY_DICT: &str = "ownership_history";
+const OWNERSHIP_TIMESTAMPS_DICT: &str = "ownership_timestamps";
+
+/// Most ownership changes kept per token; older ones are dropped as new ones are recorded.
+pub const MAX_OWNERSHIP_HISTORY: usize = 20;
+
+pub const REASON_MINT: &str = "mint";
+pub const REASON_TRANSFER: &str = "transfer";
+pub const REASON_FORCE_TRANSFER: &str = "force_transfer";
+pub const REASON_RECOVERY: &str = "recovery";
+pub const REASON_BURN: &str = "burn";
+pub const REASON_LIQUIDATION: &str = "liquidation";
+pub const REASON_SALE: &str = "sale";
+
+/// A single change of ownership. `from` is `None` on mint and `to` is `None` on burn.
+pub struct OwnershipRecord {
+    pub from: Option<Key>,
+    pub to: Option<Key>,
+    pub timestamp: u64,
+    pub reason: String,
+}
+
+impl CLTyped for OwnershipRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for OwnershipRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.from.to_bytes()?);
+        result.extend(self.to.to_bytes()?);
+        result.extend(self.timestamp.to_bytes()?);
+        result.extend(self.reason.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.from.serialized_length()
+            + self.to.serialized_length()
+            + self.timestamp.serialized_length()
+            + self.reason.serialized_length()
+    }
+}
+
+impl FromBytes for OwnershipRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (from, bytes) = Option::<Key>::from_bytes(bytes)?;
+        let (to, bytes) = Option::<Key>::from_bytes(bytes)?;
+        let (timestamp, bytes) = u64::from_bytes(bytes)?;
+        let (reason, bytes) = String::from_bytes(bytes)?;
+        Ok((
+            OwnershipRecord {
+                from,
+                to,
+                timestamp,
+                reason,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct OwnershipHistory {
+    dict: Dict,
+}
+
+impl OwnershipHistory {
+    pub fn instance() -> OwnershipHistory {
+        OwnershipHistory {
+            dict: Dict::instance(OWNERSHIP_HISTORY_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(OWNERSHIP_HISTORY_DICT)
+    }
+
+    pub fn get(&self, key: &str) -> Vec<OwnershipRecord> {
+        self.dict.get(key).unwrap_or_default()
+    }
+
+    pub fn push(&self, key: &str, record: OwnershipRecord) {
+        let mut history = self.get(key);
+        if history.len() >= MAX_OWNERSHIP_HISTORY {
+            history.remove(0);
+        }
+        history.push(record);
+        self.dict.set(key, history);
+    }
+}
+
+/// Mint and last ownership change time of each token, kept apart from the history so
+/// reading them doesn't grow with the number of transfers.
+pub struct OwnershipTimestamps {
+    dict: Dict,
+}
+
+impl OwnershipTimestamps {
+    pub fn instance() -> OwnershipTimestamps {
+        OwnershipTimestamps {
+            dict: Dict::instance(OWNERSHIP_TIMESTAMPS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(OWNERSHIP_TIMESTAMPS_DICT)
+    }
+
+    pub fn get(&self, key: &str) -> Option<(u64, u64)> {
+        self.dict.get(key)
+    }
+
+    pub fn record(&self, key: &str, timestamp: u64) {
+        let first = self.get(key).map_or(timestamp, |(first, _)| first);
+        self.dict.set(key, (first, timestamp));
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.dict.remove::<(u64, u64)>(key);
+    }
+}
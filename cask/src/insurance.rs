@@ -0,0 +1,87 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U512,
+};
+use cep47::contract_utils::Dict;
+
+const INSURANCE_DICT: &str = "insurance";
+
+/// Cover of a token. `policy_hash` is the hex encoded blake2b hash of the policy id,
+/// `coverage_value` is in motes and `expiry` in milliseconds of block time.
+pub struct InsuranceRecord {
+    pub policy_hash: String,
+    pub insurer: String,
+    pub coverage_value: U512,
+    pub expiry: u64,
+}
+
+impl CLTyped for InsuranceRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for InsuranceRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.policy_hash.to_bytes()?);
+        result.extend(self.insurer.to_bytes()?);
+        result.extend(self.coverage_value.to_bytes()?);
+        result.extend(self.expiry.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.policy_hash.serialized_length()
+            + self.insurer.serialized_length()
+            + self.coverage_value.serialized_length()
+            + self.expiry.serialized_length()
+    }
+}
+
+impl FromBytes for InsuranceRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (policy_hash, bytes) = String::from_bytes(bytes)?;
+        let (insurer, bytes) = String::from_bytes(bytes)?;
+        let (coverage_value, bytes) = U512::from_bytes(bytes)?;
+        let (expiry, bytes) = u64::from_bytes(bytes)?;
+        Ok((
+            InsuranceRecord {
+                policy_hash,
+                insurer,
+                coverage_value,
+                expiry,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct Insurance {
+    dict: Dict,
+}
+
+impl Insurance {
+    pub fn instance() -> Insurance {
+        Insurance {
+            dict: Dict::instance(INSURANCE_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(INSURANCE_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Option<InsuranceRecord> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &str, record: InsuranceRecord) {
+        self.dict.set(token_id, record);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<InsuranceRecord>(token_id);
+    }
+}
@@ -0,0 +1,50 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const ORACLES_DICT: &str = "oracles";
+pub trait OracleControl<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Oracles::init();
+    }
+
+    fn revoke_oracle(&mut self, address: Key) {
+        Oracles::instance().revoke_oracle(&address);
+    }
+
+    fn add_oracle(&mut self, address: Key) {
+        Oracles::instance().add_oracle(&address);
+    }
+
+    fn is_oracle(&self) -> bool {
+        let caller = self.get_caller();
+        Oracles::instance().is_oracle(&caller)
+    }
+}
+
+struct Oracles {
+    dict: Dict,
+}
+
+impl Oracles {
+    pub fn instance() -> Oracles {
+        Oracles {
+            dict: Dict::instance(ORACLES_DICT),
+        }
+    }
+    pub fn init() {
+        storage::new_dictionary(ORACLES_DICT).unwrap_or_revert();
+    }
+
+    pub fn is_oracle(&self, key: &Key) -> bool {
+        self.dict.get_by_key::<()>(key).is_some()
+    }
+
+    pub fn add_oracle(&self, key: &Key) {
+        self.dict.set_by_key(key, ());
+    }
+
+    pub fn revoke_oracle(&self, key: &Key) {
+        self.dict.remove_by_key::<()>(key);
+    }
+}
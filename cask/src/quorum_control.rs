@@ -0,0 +1,238 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::{
+    contract_utils::{get_key, set_key, AdminControl, ContractContext, ContractStorage, Dict},
+    Error,
+};
+
+const PROPOSALS_DICT: &str = "proposals";
+const GUARDED_ENTRY_POINTS_DICT: &str = "guarded_entry_points";
+const QUORUM_THRESHOLD: &str = "quorum_threshold";
+const PROPOSAL_COUNT: &str = "proposal_count";
+
+/// A privileged action waiting for admin approvals. `args` holds the serialized
+/// arguments of `action`, in the order the matching entry point takes them.
+#[derive(Clone)]
+pub struct Proposal {
+    pub proposer: Key,
+    pub action: String,
+    pub args: Vec<u8>,
+    pub approvals: Vec<Key>,
+    pub deadline: u64,
+    pub executed: bool,
+}
+
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.proposer.to_bytes()?);
+        result.extend(self.action.to_bytes()?);
+        result.extend(self.args.to_bytes()?);
+        result.extend(self.approvals.to_bytes()?);
+        result.extend(self.deadline.to_bytes()?);
+        result.extend(self.executed.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.proposer.serialized_length()
+            + self.action.serialized_length()
+            + self.args.serialized_length()
+            + self.approvals.serialized_length()
+            + self.deadline.serialized_length()
+            + self.executed.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (proposer, bytes) = Key::from_bytes(bytes)?;
+        let (action, bytes) = String::from_bytes(bytes)?;
+        let (args, bytes) = Vec::<u8>::from_bytes(bytes)?;
+        let (approvals, bytes) = Vec::<Key>::from_bytes(bytes)?;
+        let (deadline, bytes) = u64::from_bytes(bytes)?;
+        let (executed, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            Proposal {
+                proposer,
+                action,
+                args,
+                approvals,
+                deadline,
+                executed,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub trait QuorumControl<Storage: ContractStorage>:
+    ContractContext<Storage> + AdminControl<Storage>
+{
+    fn init(&mut self) {
+        Proposals::init();
+        GuardedEntryPoints::init();
+        set_key(QUORUM_THRESHOLD, 1u32);
+        set_key(PROPOSAL_COUNT, 0u64);
+    }
+
+    fn quorum_threshold(&self) -> u32 {
+        get_key(QUORUM_THRESHOLD).unwrap_or(1)
+    }
+
+    fn set_quorum_threshold(&mut self, threshold: u32) -> Result<(), Error> {
+        if threshold == 0 {
+            return Err(Error::WrongArguments);
+        }
+        set_key(QUORUM_THRESHOLD, threshold);
+        Ok(())
+    }
+
+    fn set_guarded_entry_point(&mut self, entry_point: String, guarded: bool) {
+        let guarded_entry_points = GuardedEntryPoints::instance();
+        if guarded {
+            guarded_entry_points.add(&entry_point);
+        } else {
+            guarded_entry_points.remove(&entry_point);
+        }
+    }
+
+    fn is_guarded(&self, entry_point: &str) -> bool {
+        self.quorum_threshold() > 1 && GuardedEntryPoints::instance().contains(entry_point)
+    }
+
+    /// Reverts when `entry_point` can only run through an approved proposal.
+    fn assert_not_guarded(&self, entry_point: &str) {
+        if self.is_guarded(entry_point) {
+            runtime::revert(Error::PermissionDenied);
+        }
+    }
+
+    fn proposal(&self, proposal_id: u64) -> Option<Proposal> {
+        Proposals::instance().get(proposal_id)
+    }
+
+    fn add_proposal(&mut self, action: String, args: Vec<u8>, deadline: u64) -> Result<u64, Error> {
+        self.assert_caller_is_admin();
+        if deadline <= u64::from(runtime::get_blocktime()) {
+            return Err(Error::WrongArguments);
+        }
+        let proposer = self.get_caller();
+        let id: u64 = get_key(PROPOSAL_COUNT).unwrap_or_default();
+        Proposals::instance().set(
+            id,
+            Proposal {
+                proposer,
+                action,
+                args,
+                approvals: vec![proposer],
+                deadline,
+                executed: false,
+            },
+        );
+        set_key(PROPOSAL_COUNT, id + 1);
+        Ok(id)
+    }
+
+    fn add_approval(&mut self, proposal_id: u64) -> Result<(), Error> {
+        self.assert_caller_is_admin();
+        let proposals = Proposals::instance();
+        let mut proposal = proposals.get(proposal_id).ok_or(Error::WrongArguments)?;
+        if proposal.executed || proposal.deadline <= u64::from(runtime::get_blocktime()) {
+            return Err(Error::PermissionDenied);
+        }
+        let approver = self.get_caller();
+        if !proposal.approvals.contains(&approver) {
+            proposal.approvals.push(approver);
+        }
+        proposals.set(proposal_id, proposal);
+        Ok(())
+    }
+
+    /// Marks the proposal executed and returns it once enough current admins approved it.
+    fn take_approved_proposal(&mut self, proposal_id: u64) -> Option<Proposal> {
+        let proposals = Proposals::instance();
+        let mut proposal = proposals.get(proposal_id)?;
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approver| self.is_admin(**approver))
+            .count();
+        if proposal.executed || approvals < self.quorum_threshold() as usize {
+            return None;
+        }
+        proposal.executed = true;
+        proposals.set(proposal_id, proposal.clone());
+        Some(proposal)
+    }
+}
+
+struct Proposals {
+    dict: Dict,
+}
+
+impl Proposals {
+    pub fn instance() -> Proposals {
+        Proposals {
+            dict: Dict::instance(PROPOSALS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(PROPOSALS_DICT)
+    }
+
+    pub fn get(&self, id: u64) -> Option<Proposal> {
+        self.dict.get(&id.to_string())
+    }
+
+    pub fn set(&self, id: u64, value: Proposal) {
+        self.dict.set(&id.to_string(), value);
+    }
+}
+
+struct GuardedEntryPoints {
+    dict: Dict,
+}
+
+impl GuardedEntryPoints {
+    pub fn instance() -> GuardedEntryPoints {
+        GuardedEntryPoints {
+            dict: Dict::instance(GUARDED_ENTRY_POINTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(GUARDED_ENTRY_POINTS_DICT)
+    }
+
+    pub fn contains(&self, entry_point: &str) -> bool {
+        self.dict.get::<()>(entry_point).is_some()
+    }
+
+    pub fn add(&self, entry_point: &str) {
+        self.dict.set(entry_point, ());
+    }
+
+    pub fn remove(&self, entry_point: &str) {
+        self.dict.remove::<()>(entry_point);
+    }
+}
+
+/// Reads the next argument of a proposal, failing on malformed input.
+pub fn next_arg<T: FromBytes>(bytes: &[u8]) -> Result<(T, &[u8]), Error> {
+    T::from_bytes(bytes).map_err(|_| Error::WrongArguments)
+}
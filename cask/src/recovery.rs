@@ -0,0 +1,86 @@
+use alloc::{string::ToString, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::{contract_utils::Dict, TokenId};
+
+const RECOVERY_REQUESTS_DICT: &str = "recovery_requests";
+
+/// Reassignment of a lost account's tokens, executable once `executable_at` has passed.
+pub struct RecoveryRequest {
+    pub old_owner: Key,
+    pub new_owner: Key,
+    pub token_ids: Vec<TokenId>,
+    pub executable_at: u64,
+}
+
+impl CLTyped for RecoveryRequest {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for RecoveryRequest {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.old_owner.to_bytes()?);
+        result.extend(self.new_owner.to_bytes()?);
+        result.extend(self.token_ids.to_bytes()?);
+        result.extend(self.executable_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.old_owner.serialized_length()
+            + self.new_owner.serialized_length()
+            + self.token_ids.serialized_length()
+            + self.executable_at.serialized_length()
+    }
+}
+
+impl FromBytes for RecoveryRequest {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (old_owner, bytes) = Key::from_bytes(bytes)?;
+        let (new_owner, bytes) = Key::from_bytes(bytes)?;
+        let (token_ids, bytes) = Vec::<TokenId>::from_bytes(bytes)?;
+        let (executable_at, bytes) = u64::from_bytes(bytes)?;
+        Ok((
+            RecoveryRequest {
+                old_owner,
+                new_owner,
+                token_ids,
+                executable_at,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct RecoveryRequests {
+    dict: Dict,
+}
+
+impl RecoveryRequests {
+    pub fn instance() -> RecoveryRequests {
+        RecoveryRequests {
+            dict: Dict::instance(RECOVERY_REQUESTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(RECOVERY_REQUESTS_DICT)
+    }
+
+    pub fn get(&self, id: u64) -> Option<RecoveryRequest> {
+        self.dict.get(&id.to_string())
+    }
+
+    pub fn set(&self, id: u64, value: RecoveryRequest) {
+        self.dict.set(&id.to_string(), value);
+    }
+
+    pub fn remove(&self, id: u64) {
+        self.dict.remove::<RecoveryRequest>(&id.to_string());
+    }
+}
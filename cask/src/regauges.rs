@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::contract_utils::Dict;
+
+const REGAUGES_DICT: &str = "regauges";
+
+/// Most regauges kept per token; older ones are dropped as new ones are logged.
+pub const MAX_REGAUGE_HISTORY: usize = 20;
+
+/// A measurement of the cask's contents. `bulk` and `lpa`, litres of pure alcohol, are in
+/// centilitres and `abv` in basis points. `correction` marks entries written by an admin
+/// to fix the log, which may raise the LPA.
+pub struct Regauge {
+    pub date: u64,
+    pub bulk: u64,
+    pub abv: u32,
+    pub lpa: u64,
+    pub custodian: Key,
+    pub correction: bool,
+}
+
+impl CLTyped for Regauge {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Regauge {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.date.to_bytes()?);
+        result.extend(self.bulk.to_bytes()?);
+        result.extend(self.abv.to_bytes()?);
+        result.extend(self.lpa.to_bytes()?);
+        result.extend(self.custodian.to_bytes()?);
+        result.extend(self.correction.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.date.serialized_length()
+            + self.bulk.serialized_length()
+            + self.abv.serialized_length()
+            + self.lpa.serialized_length()
+            + self.custodian.serialized_length()
+            + self.correction.serialized_length()
+    }
+}
+
+impl FromBytes for Regauge {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (date, bytes) = u64::from_bytes(bytes)?;
+        let (bulk, bytes) = u64::from_bytes(bytes)?;
+        let (abv, bytes) = u32::from_bytes(bytes)?;
+        let (lpa, bytes) = u64::from_bytes(bytes)?;
+        let (custodian, bytes) = Key::from_bytes(bytes)?;
+        let (correction, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            Regauge {
+                date,
+                bulk,
+                abv,
+                lpa,
+                custodian,
+                correction,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct Regauges {
+    dict: Dict,
+}
+
+impl Regauges {
+    pub fn instance() -> Regauges {
+        Regauges {
+            dict: Dict::instance(REGAUGES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(REGAUGES_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Vec<Regauge> {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn push(&self, token_id: &str, regauge: Regauge) {
+        let mut regauges = self.get(token_id);
+        if regauges.len() >= MAX_REGAUGE_HISTORY {
+            regauges.remove(0);
+        }
+        regauges.push(regauge);
+        self.dict.set(token_id, regauges);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Vec<Regauge>>(token_id);
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key,
+};
+use cep47::Meta;
+
+use crate::Commission;
+
+/// Everything a client needs to display a token, read in a single call.
+pub struct TokenInfo {
+    pub owner: Key,
+    pub meta: Meta,
+    pub commission: Commission,
+    pub commission_source: String,
+    pub status: String,
+    pub minted_at: u64,
+    pub last_transferred_at: u64,
+    pub locked: bool,
+}
+
+impl CLTyped for TokenInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TokenInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.owner.to_bytes()?);
+        result.extend(self.meta.to_bytes()?);
+        result.extend(self.commission.to_bytes()?);
+        result.extend(self.commission_source.to_bytes()?);
+        result.extend(self.status.to_bytes()?);
+        result.extend(self.minted_at.to_bytes()?);
+        result.extend(self.last_transferred_at.to_bytes()?);
+        result.extend(self.locked.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.owner.serialized_length()
+            + self.meta.serialized_length()
+            + self.commission.serialized_length()
+            + self.commission_source.serialized_length()
+            + self.status.serialized_length()
+            + self.minted_at.serialized_length()
+            + self.last_transferred_at.serialized_length()
+            + self.locked.serialized_length()
+    }
+}
+
+impl FromBytes for TokenInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (owner, bytes) = Key::from_bytes(bytes)?;
+        let (meta, bytes) = Meta::from_bytes(bytes)?;
+        let (commission, bytes) = Commission::from_bytes(bytes)?;
+        let (commission_source, bytes) = String::from_bytes(bytes)?;
+        let (status, bytes) = String::from_bytes(bytes)?;
+        let (minted_at, bytes) = u64::from_bytes(bytes)?;
+        let (last_transferred_at, bytes) = u64::from_bytes(bytes)?;
+        let (locked, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            TokenInfo {
+                owner,
+                meta,
+                commission,
+                commission_source,
+                status,
+                minted_at,
+                last_transferred_at,
+                locked,
+            },
+            bytes,
+        ))
+    }
+}
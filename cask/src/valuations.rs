@@ -0,0 +1,132 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U512,
+};
+use cep47::contract_utils::Dict;
+
+const VALUATIONS_DICT: &str = "valuations";
+const OWNER_VALUATIONS_DICT: &str = "owner_valuations";
+
+/// Most valuations kept per token; older ones are dropped as new ones are published.
+pub const MAX_VALUATION_HISTORY: usize = 20;
+
+/// An oracle's valuation of a token, in motes as of `timestamp`.
+pub struct Valuation {
+    pub value: U512,
+    pub timestamp: u64,
+    pub source: String,
+    pub oracle: Key,
+}
+
+impl CLTyped for Valuation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Valuation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.value.to_bytes()?);
+        result.extend(self.timestamp.to_bytes()?);
+        result.extend(self.source.to_bytes()?);
+        result.extend(self.oracle.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.value.serialized_length()
+            + self.timestamp.serialized_length()
+            + self.source.serialized_length()
+            + self.oracle.serialized_length()
+    }
+}
+
+impl FromBytes for Valuation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (value, bytes) = U512::from_bytes(bytes)?;
+        let (timestamp, bytes) = u64::from_bytes(bytes)?;
+        let (source, bytes) = String::from_bytes(bytes)?;
+        let (oracle, bytes) = Key::from_bytes(bytes)?;
+        Ok((
+            Valuation {
+                value,
+                timestamp,
+                source,
+                oracle,
+            },
+            bytes,
+        ))
+    }
+}
+
+pub struct Valuations {
+    dict: Dict,
+}
+
+impl Valuations {
+    pub fn instance() -> Valuations {
+        Valuations {
+            dict: Dict::instance(VALUATIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(VALUATIONS_DICT)
+    }
+
+    pub fn get(&self, token_id: &str) -> Vec<Valuation> {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn latest_value(&self, token_id: &str) -> U512 {
+        self.get(token_id)
+            .last()
+            .map(|valuation| valuation.value)
+            .unwrap_or_default()
+    }
+
+    pub fn push(&self, token_id: &str, valuation: Valuation) {
+        let mut valuations = self.get(token_id);
+        if valuations.len() >= MAX_VALUATION_HISTORY {
+            valuations.remove(0);
+        }
+        valuations.push(valuation);
+        self.dict.set(token_id, valuations);
+    }
+
+    pub fn remove(&self, token_id: &str) {
+        self.dict.remove::<Vec<Valuation>>(token_id);
+    }
+}
+
+/// Sum of the latest valuations of the tokens each account owns.
+pub struct OwnerValuations {
+    dict: Dict,
+}
+
+impl OwnerValuations {
+    pub fn instance() -> OwnerValuations {
+        OwnerValuations {
+            dict: Dict::instance(OWNER_VALUATIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(OWNER_VALUATIONS_DICT)
+    }
+
+    pub fn get(&self, owner: &Key) -> U512 {
+        self.dict.get_by_key(owner).unwrap_or_default()
+    }
+
+    pub fn add(&self, owner: &Key, value: U512) {
+        self.dict.set_by_key(owner, self.get(owner) + value);
+    }
+
+    pub fn sub(&self, owner: &Key, value: U512) {
+        self.dict
+            .set_by_key(owner, self.get(owner).saturating_sub(value));
+    }
+}
@@ -6,7 +6,9 @@ use blake2::{
     VarBlake2b,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::ToBytes, runtime_args, CLTyped, Key, RuntimeArgs, U256,
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    runtime_args, CLType, CLTyped, Key, RuntimeArgs, URef, U256, U512,
 };
 use test_env::{TestContract, TestEnv};
 
@@ -14,6 +16,301 @@ pub type TokenId = String;
 pub type Meta = BTreeMap<String, String>;
 pub type Commission = BTreeMap<String, String>;
 
+#[derive(Debug, PartialEq)]
+pub struct OwnershipRecord {
+    pub from: Option<Key>,
+    pub to: Option<Key>,
+    pub timestamp: u64,
+    pub reason: String,
+}
+
+impl CLTyped for OwnershipRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for OwnershipRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (from, bytes) = Option::<Key>::from_bytes(bytes)?;
+        let (to, bytes) = Option::<Key>::from_bytes(bytes)?;
+        let (timestamp, bytes) = u64::from_bytes(bytes)?;
+        let (reason, bytes) = String::from_bytes(bytes)?;
+        Ok((
+            OwnershipRecord {
+                from,
+                to,
+                timestamp,
+                reason,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Attestation {
+    pub warehouse_id: String,
+    pub inspection_date: u64,
+    pub volume: u64,
+    pub abv: u32,
+    pub document_hash: String,
+    pub custodian: Key,
+    pub posted_at: u64,
+}
+
+impl CLTyped for Attestation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for Attestation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (warehouse_id, bytes) = String::from_bytes(bytes)?;
+        let (inspection_date, bytes) = u64::from_bytes(bytes)?;
+        let (volume, bytes) = u64::from_bytes(bytes)?;
+        let (abv, bytes) = u32::from_bytes(bytes)?;
+        let (document_hash, bytes) = String::from_bytes(bytes)?;
+        let (custodian, bytes) = Key::from_bytes(bytes)?;
+        let (posted_at, bytes) = u64::from_bytes(bytes)?;
+        Ok((
+            Attestation {
+                warehouse_id,
+                inspection_date,
+                volume,
+                abv,
+                document_hash,
+                custodian,
+                posted_at,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TokenDocument {
+    pub doc_type: String,
+    pub hash: String,
+    pub uri: String,
+    pub added_by: Key,
+    pub added_at: u64,
+    pub revoked: bool,
+    pub replaced_by: Option<u32>,
+}
+
+impl CLTyped for TokenDocument {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for TokenDocument {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (doc_type, bytes) = String::from_bytes(bytes)?;
+        let (hash, bytes) = String::from_bytes(bytes)?;
+        let (uri, bytes) = String::from_bytes(bytes)?;
+        let (added_by, bytes) = Key::from_bytes(bytes)?;
+        let (added_at, bytes) = u64::from_bytes(bytes)?;
+        let (revoked, bytes) = bool::from_bytes(bytes)?;
+        let (replaced_by, bytes) = Option::<u32>::from_bytes(bytes)?;
+        Ok((
+            TokenDocument {
+                doc_type,
+                hash,
+                uri,
+                added_by,
+                added_at,
+                revoked,
+                replaced_by,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Regauge {
+    pub date: u64,
+    pub bulk: u64,
+    pub abv: u32,
+    pub lpa: u64,
+    pub custodian: Key,
+    pub correction: bool,
+}
+
+impl CLTyped for Regauge {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for Regauge {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (date, bytes) = u64::from_bytes(bytes)?;
+        let (bulk, bytes) = u64::from_bytes(bytes)?;
+        let (abv, bytes) = u32::from_bytes(bytes)?;
+        let (lpa, bytes) = u64::from_bytes(bytes)?;
+        let (custodian, bytes) = Key::from_bytes(bytes)?;
+        let (correction, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            Regauge {
+                date,
+                bulk,
+                abv,
+                lpa,
+                custodian,
+                correction,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InsuranceRecord {
+    pub policy_hash: String,
+    pub insurer: String,
+    pub coverage_value: U512,
+    pub expiry: u64,
+}
+
+impl CLTyped for InsuranceRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for InsuranceRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (policy_hash, bytes) = String::from_bytes(bytes)?;
+        let (insurer, bytes) = String::from_bytes(bytes)?;
+        let (coverage_value, bytes) = U512::from_bytes(bytes)?;
+        let (expiry, bytes) = u64::from_bytes(bytes)?;
+        Ok((
+            InsuranceRecord {
+                policy_hash,
+                insurer,
+                coverage_value,
+                expiry,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Valuation {
+    pub value: U512,
+    pub timestamp: u64,
+    pub source: String,
+    pub oracle: Key,
+}
+
+impl CLTyped for Valuation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for Valuation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (value, bytes) = U512::from_bytes(bytes)?;
+        let (timestamp, bytes) = u64::from_bytes(bytes)?;
+        let (source, bytes) = String::from_bytes(bytes)?;
+        let (oracle, bytes) = Key::from_bytes(bytes)?;
+        Ok((
+            Valuation {
+                value,
+                timestamp,
+                source,
+                oracle,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TokenInfo {
+    pub owner: Key,
+    pub meta: Meta,
+    pub commission: Commission,
+    pub commission_source: String,
+    pub status: String,
+    pub minted_at: u64,
+    pub last_transferred_at: u64,
+    pub locked: bool,
+}
+
+impl CLTyped for TokenInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for TokenInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (owner, bytes) = Key::from_bytes(bytes)?;
+        let (meta, bytes) = Meta::from_bytes(bytes)?;
+        let (commission, bytes) = Commission::from_bytes(bytes)?;
+        let (commission_source, bytes) = String::from_bytes(bytes)?;
+        let (status, bytes) = String::from_bytes(bytes)?;
+        let (minted_at, bytes) = u64::from_bytes(bytes)?;
+        let (last_transferred_at, bytes) = u64::from_bytes(bytes)?;
+        let (locked, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            TokenInfo {
+                owner,
+                meta,
+                commission,
+                commission_source,
+                status,
+                minted_at,
+                last_transferred_at,
+                locked,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Collection {
+    pub name: String,
+    pub meta: Meta,
+    pub commission: Commission,
+    pub cap: Option<U256>,
+    pub minters: Vec<Key>,
+}
+
+impl CLTyped for Collection {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for Collection {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, bytes) = String::from_bytes(bytes)?;
+        let (meta, bytes) = Meta::from_bytes(bytes)?;
+        let (commission, bytes) = Commission::from_bytes(bytes)?;
+        let (cap, bytes) = Option::<U256>::from_bytes(bytes)?;
+        let (minters, bytes) = Vec::<Key>::from_bytes(bytes)?;
+        Ok((
+            Collection {
+                name,
+                meta,
+                commission,
+                cap,
+                minters,
+            },
+            bytes,
+        ))
+    }
+}
+
 pub struct CaskInstance(TestContract);
 pub struct CivicInstance(TestContract);
 
@@ -106,6 +403,53 @@ impl CaskInstance {
         );
     }
 
+    pub fn grant_compliance<T: Into<Key>>(&self, sender: AccountHash, compliance: T) {
+        self.0.call_contract(
+            sender,
+            "grant_compliance",
+            runtime_args! {
+            "compliance" => compliance.into()},
+        );
+    }
+
+    pub fn grant_custodian<T: Into<Key>>(&self, sender: AccountHash, custodian: T) {
+        self.0.call_contract(
+            sender,
+            "grant_custodian",
+            runtime_args! {
+                "custodian" => custodian.into()
+            },
+        );
+    }
+
+    pub fn grant_oracle<T: Into<Key>>(&self, sender: AccountHash, oracle: T) {
+        self.0.call_contract(
+            sender,
+            "grant_oracle",
+            runtime_args! {
+                "oracle" => oracle.into()
+            },
+        );
+    }
+
+    pub fn add_to_denylist<T: Into<Key>>(&self, sender: AccountHash, account: T) {
+        self.0.call_contract(
+            sender,
+            "add_to_denylist",
+            runtime_args! {
+            "account" => account.into()},
+        );
+    }
+
+    pub fn remove_from_denylist<T: Into<Key>>(&self, sender: AccountHash, account: T) {
+        self.0.call_contract(
+            sender,
+            "remove_from_denylist",
+            runtime_args! {
+            "account" => account.into()},
+        );
+    }
+
     pub fn mint<T: Into<Key>>(
         &self,
         sender: AccountHash,
@@ -126,6 +470,137 @@ impl CaskInstance {
         )
     }
 
+    pub fn mint_at<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        token_commissions: Vec<Commission>,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "mint",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids,
+                "token_metas" => token_metas,
+                "token_commissions" => token_commissions
+            },
+            block_time,
+        )
+    }
+
+    pub fn mint_soulbound<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        token_commissions: Vec<Commission>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint_soulbound",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids,
+                "token_metas" => token_metas,
+                "token_commissions" => token_commissions
+            },
+        )
+    }
+
+    pub fn create_collection(
+        &self,
+        sender: AccountHash,
+        collection_id: &str,
+        name: &str,
+        commission: Commission,
+        cap: Option<U256>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "create_collection",
+            runtime_args! {
+                "collection_id" => collection_id.to_string(),
+                "name" => name.to_string(),
+                "meta" => Meta::new(),
+                "commission" => commission,
+                "cap" => cap
+            },
+        )
+    }
+
+    pub fn update_collection(
+        &self,
+        sender: AccountHash,
+        collection_id: &str,
+        name: &str,
+        commission: Commission,
+    ) {
+        self.0.call_contract(
+            sender,
+            "update_collection",
+            runtime_args! {
+                "collection_id" => collection_id.to_string(),
+                "name" => name.to_string(),
+                "meta" => Meta::new(),
+                "commission" => commission
+            },
+        )
+    }
+
+    pub fn grant_collection_minter<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        collection_id: &str,
+        minter: T,
+    ) {
+        self.0.call_contract(
+            sender,
+            "grant_collection_minter",
+            runtime_args! {
+                "collection_id" => collection_id.to_string(),
+                "minter" => minter.into()
+            },
+        )
+    }
+
+    pub fn mint_to_collection<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        collection_id: &str,
+        recipient: T,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        token_commissions: Vec<Commission>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint_to_collection",
+            runtime_args! {
+                "collection_id" => collection_id.to_string(),
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids,
+                "token_metas" => token_metas,
+                "token_commissions" => token_commissions
+            },
+        )
+    }
+
+    pub fn set_supply_cap(&self, sender: AccountHash, collection_id: Option<&str>, cap: U256) {
+        self.0.call_contract(
+            sender,
+            "set_supply_cap",
+            runtime_args! {
+                "collection_id" => collection_id.map(String::from),
+                "cap" => cap
+            },
+        )
+    }
+
     pub fn mint_copies<T: Into<Key>>(
         &self,
         sender: AccountHash,
@@ -164,6 +639,24 @@ impl CaskInstance {
         )
     }
 
+    pub fn transfer_at<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_ids: Vec<TokenId>,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids
+            },
+            block_time,
+        )
+    }
+
     pub fn transfer_from<T: Into<Key>>(
         &self,
         sender: AccountHash,
@@ -182,6 +675,26 @@ impl CaskInstance {
         )
     }
 
+    pub fn force_transfer<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        owner: T,
+        recipient: T,
+        token_ids: Vec<TokenId>,
+        reason: &str,
+    ) {
+        self.0.call_contract(
+            sender,
+            "force_transfer",
+            runtime_args! {
+                "sender" => owner.into(),
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids,
+                "reason" => reason
+            },
+        )
+    }
+
     pub fn set_token_meta(&self, sender: AccountHash, token_id: TokenId, token_meta: Meta) {
         self.0.call_contract(
             sender,
@@ -233,37 +746,835 @@ impl CaskInstance {
         )
     }
 
-    pub fn burn<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_ids: Vec<TokenId>) {
+    pub fn set_quorum_threshold(&self, sender: AccountHash, threshold: u32) {
         self.0.call_contract(
             sender,
-            "burn",
+            "set_quorum_threshold",
             runtime_args! {
-                "owner" => owner.into(),
-                "token_ids" => token_ids
+                "threshold" => threshold
             },
         )
     }
 
-    pub fn is_admin<T: Into<Key>>(&self, account: T) -> bool {
-        self.0
-            .query_dictionary::<()>("admins", key_to_str(&account.into()))
-            .is_some()
-    }
-
-    pub fn is_minter<T: Into<Key>>(&self, account: T) -> bool {
-        self.0
-            .query_dictionary::<()>("minters", key_to_str(&account.into()))
-            .is_some()
+    pub fn set_guarded_entry_point(&self, sender: AccountHash, entry_point: &str, guarded: bool) {
+        self.0.call_contract(
+            sender,
+            "set_guarded_entry_point",
+            runtime_args! {
+                "entry_point" => entry_point,
+                "guarded" => guarded
+            },
+        )
     }
 
-    pub fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
-        self.0.query_dictionary("metadata", token_id)
+    pub fn propose(
+        &self,
+        sender: AccountHash,
+        action: &str,
+        args: Vec<u8>,
+        deadline: u64,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "propose",
+            runtime_args! {
+                "action" => action,
+                "args" => args,
+                "deadline" => deadline
+            },
+            block_time,
+        )
+    }
+
+    pub fn approve_proposal(&self, sender: AccountHash, proposal_id: u64, block_time: u64) {
+        self.0.call_contract_at(
+            sender,
+            "approve_proposal",
+            runtime_args! {
+                "proposal_id" => proposal_id
+            },
+            block_time,
+        )
+    }
+
+    pub fn set_recovery_delay(&self, sender: AccountHash, delay: u64) {
+        self.0.call_contract(
+            sender,
+            "set_recovery_delay",
+            runtime_args! {
+                "delay" => delay
+            },
+        )
+    }
+
+    pub fn request_recovery<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        old_owner: T,
+        new_owner: T,
+        token_ids: Vec<TokenId>,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "request_recovery",
+            runtime_args! {
+                "old_owner" => old_owner.into(),
+                "new_owner" => new_owner.into(),
+                "token_ids" => token_ids
+            },
+            block_time,
+        )
+    }
+
+    pub fn cancel_recovery(&self, sender: AccountHash, request_id: u64) {
+        self.0.call_contract(
+            sender,
+            "cancel_recovery",
+            runtime_args! {
+                "request_id" => request_id
+            },
+        )
+    }
+
+    pub fn execute_recovery(&self, sender: AccountHash, request_id: u64, block_time: u64) {
+        self.0.call_contract_at(
+            sender,
+            "execute_recovery",
+            runtime_args! {
+                "request_id" => request_id
+            },
+            block_time,
+        )
+    }
+
+    /// Posts the warehouse fields of `attestation`; the contract fills in the custodian
+    /// and the posting time.
+    pub fn post_attestation(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        attestation: &Attestation,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "post_attestation",
+            runtime_args! {
+                "token_id" => token_id,
+                "warehouse_id" => attestation.warehouse_id.clone(),
+                "inspection_date" => attestation.inspection_date,
+                "volume" => attestation.volume,
+                "abv" => attestation.abv,
+                "document_hash" => attestation.document_hash.clone()
+            },
+            block_time,
+        )
+    }
+
+    /// Logs the measurements of `regauge` through `add_regauge`, or `correct_regauge`
+    /// when it is a correction; the contract fills in the custodian.
+    pub fn add_regauge(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        regauge: &Regauge,
+        block_time: u64,
+    ) {
+        let entry_point = if regauge.correction {
+            "correct_regauge"
+        } else {
+            "add_regauge"
+        };
+        self.0.call_contract_at(
+            sender,
+            entry_point,
+            runtime_args! {
+                "token_id" => token_id,
+                "date" => regauge.date,
+                "bulk" => regauge.bulk,
+                "abv" => regauge.abv,
+                "lpa" => regauge.lpa
+            },
+            block_time,
+        )
+    }
+
+    pub fn publish_valuation(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        value: U512,
+        timestamp: u64,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "publish_valuation",
+            runtime_args! {
+                "token_id" => token_id,
+                "value" => value,
+                "timestamp" => timestamp,
+                "source" => "auction".to_string()
+            },
+            block_time,
+        )
+    }
+
+    pub fn set_attestation_max_age(&self, sender: AccountHash, max_age: u64) {
+        self.0.call_contract(
+            sender,
+            "set_attestation_max_age",
+            runtime_args! {
+                "max_age" => max_age
+            },
+        )
+    }
+
+    pub fn set_block_stale_transfers(&self, sender: AccountHash, block: bool) {
+        self.0.call_contract(
+            sender,
+            "set_block_stale_transfers",
+            runtime_args! {
+                "block" => block
+            },
+        )
+    }
+
+    pub fn add_token_document(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        doc_type: &str,
+        hash: String,
+        uri: &str,
+    ) {
+        self.0.call_contract(
+            sender,
+            "add_token_document",
+            runtime_args! {
+                "token_id" => token_id,
+                "doc_type" => doc_type.to_string(),
+                "hash" => hash,
+                "uri" => uri.to_string()
+            },
+        )
+    }
+
+    pub fn revoke_token_document(&self, sender: AccountHash, token_id: TokenId, index: u32) {
+        self.0.call_contract(
+            sender,
+            "revoke_token_document",
+            runtime_args! {
+                "token_id" => token_id,
+                "index" => index
+            },
+        )
+    }
+
+    pub fn replace_token_document(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        index: u32,
+        hash: String,
+        uri: &str,
+    ) {
+        self.0.call_contract(
+            sender,
+            "replace_token_document",
+            runtime_args! {
+                "token_id" => token_id,
+                "index" => index,
+                "hash" => hash,
+                "uri" => uri.to_string()
+            },
+        )
+    }
+
+    pub fn set_fill_timestamp(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        fill_timestamp: u64,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "set_fill_timestamp",
+            runtime_args! {
+                "token_id" => token_id,
+                "fill_timestamp" => fill_timestamp
+            },
+            block_time,
+        )
+    }
+
+    pub fn set_min_transfer_age(&self, sender: AccountHash, min_age: u64) {
+        self.0.call_contract(
+            sender,
+            "set_min_transfer_age",
+            runtime_args! {
+                "min_age" => min_age
+            },
+        )
+    }
+
+    pub fn set_token_insurance(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        insurance: &InsuranceRecord,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_token_insurance",
+            runtime_args! {
+                "token_id" => token_id,
+                "policy_hash" => insurance.policy_hash.clone(),
+                "insurer" => insurance.insurer.clone(),
+                "coverage_value" => insurance.coverage_value,
+                "expiry" => insurance.expiry
+            },
+        )
+    }
+
+    pub fn insurance_expiring_before(
+        &self,
+        timestamp: u64,
+        offset: U256,
+        limit: u32,
+    ) -> Vec<TokenId> {
+        self.0.call_getter(
+            "insurance_expiring_before",
+            runtime_args! {
+                "timestamp" => timestamp,
+                "offset" => offset,
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn set_require_insurance(&self, sender: AccountHash, require: bool) {
+        self.0.call_contract(
+            sender,
+            "set_require_insurance",
+            runtime_args! {
+                "require" => require
+            },
+        )
+    }
+
+    pub fn set_token_jurisdictions(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        jurisdictions: Vec<String>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_token_jurisdictions",
+            runtime_args! {
+                "token_id" => token_id,
+                "jurisdictions" => jurisdictions
+            },
+        )
+    }
+
+    pub fn set_token_id_keys(&self, sender: AccountHash, keys: Vec<String>) {
+        self.0.call_contract(
+            sender,
+            "set_token_id_keys",
+            runtime_args! {
+                "keys" => keys
+            },
+        )
+    }
+
+    pub fn set_unique_meta_key(&self, sender: AccountHash, key: &str) {
+        self.0.call_contract(
+            sender,
+            "set_unique_meta_key",
+            runtime_args! {
+                "key" => key.to_string()
+            },
+        )
+    }
+
+    pub fn set_default_commission(&self, sender: AccountHash, commission: Commission) {
+        self.0.call_contract(
+            sender,
+            "set_default_commission",
+            runtime_args! {
+                "commission" => commission
+            },
+        )
+    }
+
+    pub fn clear_token_commission(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "clear_token_commission",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn split_into_bottles(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        count: u32,
+        bottle_meta: Meta,
+    ) {
+        self.0.call_contract(
+            sender,
+            "split_into_bottles",
+            runtime_args! {
+                "token_id" => token_id,
+                "count" => count,
+                "bottle_meta" => bottle_meta
+            },
+        )
+    }
+
+    pub fn split_into_bottles_at(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        count: u32,
+        bottle_meta: Meta,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "split_into_bottles",
+            runtime_args! {
+                "token_id" => token_id,
+                "count" => count,
+                "bottle_meta" => bottle_meta
+            },
+            block_time,
+        )
+    }
+
+    pub fn merge_tokens(
+        &self,
+        sender: AccountHash,
+        token_ids: Vec<TokenId>,
+        new_meta: Meta,
+        commission_policy: &str,
+    ) {
+        self.0.call_contract(
+            sender,
+            "merge_tokens",
+            runtime_args! {
+                "token_ids" => token_ids,
+                "new_meta" => new_meta,
+                "commission_policy" => commission_policy.to_string()
+            },
+        )
+    }
+
+    pub fn merge_tokens_at(
+        &self,
+        sender: AccountHash,
+        token_ids: Vec<TokenId>,
+        new_meta: Meta,
+        commission_policy: &str,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "merge_tokens",
+            runtime_args! {
+                "token_ids" => token_ids,
+                "new_meta" => new_meta,
+                "commission_policy" => commission_policy.to_string()
+            },
+            block_time,
+        )
+    }
+
+    pub fn burn<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_ids: Vec<TokenId>) {
+        self.0.call_contract(
+            sender,
+            "burn",
+            runtime_args! {
+                "owner" => owner.into(),
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn burn_at<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        owner: T,
+        token_ids: Vec<TokenId>,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "burn",
+            runtime_args! {
+                "owner" => owner.into(),
+                "token_ids" => token_ids
+            },
+            block_time,
+        )
+    }
+
+    pub fn settle_sale<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        seller: T,
+        buyer: T,
+        amount: U512,
+        purse: URef,
+    ) {
+        self.0.call_contract(
+            sender,
+            "settle_sale",
+            runtime_args! {
+                "token_id" => token_id,
+                "seller" => seller.into(),
+                "buyer" => buyer.into(),
+                "amount" => amount,
+                "purse" => purse
+            },
+        )
+    }
+
+    pub fn withdraw(&self, sender: AccountHash, amount: U512, purse: Option<URef>) {
+        self.0.call_contract(
+            sender,
+            "withdraw",
+            runtime_args! {
+                "amount" => amount,
+                "purse" => purse
+            },
+        )
+    }
+
+    pub fn lock_as_collateral<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        lender: T,
+    ) {
+        self.0.call_contract(
+            sender,
+            "lock_as_collateral",
+            runtime_args! {
+                "token_id" => token_id,
+                "lender" => lender.into()
+            },
+        )
+    }
+
+    pub fn set_user<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        user: T,
+        expires: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_user",
+            runtime_args! {
+                "token_id" => token_id,
+                "user" => user.into(),
+                "expires" => expires
+            },
+        )
+    }
+
+    pub fn set_user_at<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        user: T,
+        expires: u64,
+        block_time: u64,
+    ) {
+        self.0.call_contract_at(
+            sender,
+            "set_user",
+            runtime_args! {
+                "token_id" => token_id,
+                "user" => user.into(),
+                "expires" => expires
+            },
+            block_time,
+        )
+    }
+
+    pub fn user_of_at(&self, token_id: TokenId, block_time: u64) -> Option<Key> {
+        self.0.call_getter_at(
+            "user_of",
+            runtime_args! {
+                "token_id" => token_id
+            },
+            block_time,
+        )
+    }
+
+    pub fn clear_collateral_lock(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "clear_collateral_lock",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn release(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "release",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn liquidate<T: Into<Key>>(&self, sender: AccountHash, token_id: TokenId, recipient: T) {
+        self.0.call_contract(
+            sender,
+            "liquidate",
+            runtime_args! {
+                "token_id" => token_id,
+                "recipient" => recipient.into()
+            },
+        )
+    }
+
+    pub fn set_storage_fee(&self, sender: AccountHash, fee: U512) {
+        self.0.call_contract(
+            sender,
+            "set_storage_fee",
+            runtime_args! {
+                "fee" => fee
+            },
+        )
+    }
+
+    pub fn set_storage_fee_at(&self, sender: AccountHash, fee: U512, block_time: u64) {
+        self.0.call_contract_at(
+            sender,
+            "set_storage_fee",
+            runtime_args! {
+                "fee" => fee
+            },
+            block_time,
+        )
+    }
+
+    pub fn storage_fees_owed_at(&self, token_id: TokenId, block_time: u64) -> U512 {
+        self.0.call_getter_at(
+            "storage_fees_owed",
+            runtime_args! {
+                "token_id" => token_id
+            },
+            block_time,
+        )
+    }
+
+    pub fn set_storage_fee_grace_period(&self, sender: AccountHash, grace_period: u64) {
+        self.0.call_contract(
+            sender,
+            "set_storage_fee_grace_period",
+            runtime_args! {
+                "grace_period" => grace_period
+            },
+        )
+    }
+
+    pub fn set_block_unpaid_transfers(&self, sender: AccountHash, block: bool) {
+        self.0.call_contract(
+            sender,
+            "set_block_unpaid_transfers",
+            runtime_args! {
+                "block" => block
+            },
+        )
+    }
+
+    pub fn set_treasury<T: Into<Key>>(&self, sender: AccountHash, treasury: T) {
+        self.0.call_contract(
+            sender,
+            "set_treasury",
+            runtime_args! {
+                "treasury" => treasury.into()
+            },
+        )
+    }
+
+    pub fn owner_burn<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        owner: T,
+        token_ids: Vec<TokenId>,
+        reason: Option<String>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "owner_burn",
+            runtime_args! {
+                "owner" => owner.into(),
+                "token_ids" => token_ids,
+                "reason" => reason
+            },
+        )
+    }
+
+    pub fn approve<T: Into<Key>>(&self, sender: AccountHash, spender: T, token_ids: Vec<TokenId>) {
+        self.0.call_contract(
+            sender,
+            "approve",
+            runtime_args! {
+                "spender" => spender.into(),
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn is_admin<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("admins", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn is_minter<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("minters", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn is_compliance<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("compliance_officers", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn is_denylisted<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("denylist", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
+        self.0.query_dictionary("metadata", token_id)
     }
 
     pub fn token_commission(&self, token_id: TokenId) -> Option<Commission> {
         self.0.query_dictionary("commissions", token_id)
     }
 
+    pub fn effective_commission(&self, token_id: TokenId) -> Option<(Commission, String)> {
+        self.0.call_getter(
+            "token_commission",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn token_metas(&self, token_ids: Vec<TokenId>) -> Vec<Option<Meta>> {
+        self.0.call_getter(
+            "token_metas",
+            runtime_args! {
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn token_commissions(&self, token_ids: Vec<TokenId>) -> Vec<Option<(Commission, String)>> {
+        self.0.call_getter(
+            "token_commissions",
+            runtime_args! {
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn token_info(&self, token_id: TokenId) -> Option<TokenInfo> {
+        self.0.call_getter(
+            "token_info",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn ownership_history(&self, token_id: TokenId) -> Vec<OwnershipRecord> {
+        self.0
+            .query_dictionary("ownership_history", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn token_attestations(&self, token_id: TokenId) -> Vec<Attestation> {
+        self.0
+            .query_dictionary("attestations", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn token_regauges(&self, token_id: TokenId) -> Vec<Regauge> {
+        self.0
+            .query_dictionary("regauges", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn token_valuations(&self, token_id: TokenId) -> Vec<Valuation> {
+        self.0
+            .query_dictionary("valuations", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn owner_valuation<T: Into<Key>>(&self, owner: T) -> U512 {
+        self.0
+            .query_dictionary("owner_valuations", key_to_str(&owner.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn token_documents(&self, token_id: TokenId) -> Vec<TokenDocument> {
+        self.0
+            .query_dictionary("token_documents", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn fill_timestamp(&self, token_id: TokenId) -> Option<u64> {
+        self.0.query_dictionary("fill_timestamps", token_id)
+    }
+
+    pub fn token_insurance(&self, token_id: TokenId) -> Option<InsuranceRecord> {
+        self.0.query_dictionary("insurance", token_id)
+    }
+
+    pub fn token_jurisdictions(&self, token_id: TokenId) -> Option<Vec<String>> {
+        self.0.query_dictionary("token_jurisdictions", token_id)
+    }
+
+    pub fn default_commission(&self) -> Commission {
+        self.0.query_named_key(String::from("default_commission"))
+    }
+
+    pub fn contract_purse(&self) -> URef {
+        self.0.query_named_key(String::from("contract_purse"))
+    }
+
+    pub fn balance_owed<T: Into<Key>>(&self, account: T) -> U512 {
+        self.0
+            .query_dictionary("balances_owed", key_to_str(&account.into()))
+            .unwrap_or_default()
+    }
+
     pub fn get_token_by_index<T: Into<Key>>(&self, account: T, index: U256) -> Option<TokenId> {
         self.0.query_dictionary(
             "owned_tokens_by_index",
@@ -271,6 +1582,97 @@ impl CaskInstance {
         )
     }
 
+    pub fn token_by_index(&self, index: U256) -> Option<TokenId> {
+        self.0.query_dictionary("all_tokens", index.to_string())
+    }
+
+    pub fn tokens_of<T: Into<Key>>(&self, owner: T, offset: U256, limit: u32) -> Vec<TokenId> {
+        self.0.call_getter(
+            "tokens_of",
+            runtime_args! {
+                "owner" => owner.into(),
+                "offset" => offset,
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn all_tokens(&self, offset: U256, limit: u32) -> Vec<TokenId> {
+        self.0.call_getter(
+            "all_tokens",
+            runtime_args! {
+                "offset" => offset,
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn children_of(&self, token_id: TokenId) -> Vec<TokenId> {
+        self.0
+            .query_dictionary("token_children", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn parent_of(&self, token_id: TokenId) -> Option<TokenId> {
+        self.0.query_dictionary("token_parents", token_id)
+    }
+
+    pub fn lineage_of(&self, token_id: TokenId) -> Vec<TokenId> {
+        self.0
+            .query_dictionary("token_lineage", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn collateral_lender(&self, token_id: TokenId) -> Option<Key> {
+        self.0.query_dictionary("collateral_locks", token_id)
+    }
+
+    pub fn token_user(&self, token_id: TokenId) -> Option<(Key, u64)> {
+        self.0.query_dictionary("token_users", token_id)
+    }
+
+    pub fn is_soulbound(&self, token_id: TokenId) -> bool {
+        self.0
+            .query_dictionary("soulbound_tokens", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn collection_of(&self, token_id: TokenId) -> Option<String> {
+        self.0.query_dictionary("token_collections", token_id)
+    }
+
+    pub fn collection(&self, collection_id: &str) -> Option<Collection> {
+        self.0
+            .query_dictionary("collections", collection_id.to_string())
+    }
+
+    pub fn remaining_supply(&self, collection_id: Option<&str>) -> Option<U256> {
+        self.0.call_getter(
+            "remaining_supply",
+            runtime_args! {
+                "collection_id" => collection_id.map(String::from)
+            },
+        )
+    }
+
+    pub fn collection_supply(&self, collection_id: &str) -> U256 {
+        self.0
+            .query_dictionary("collection_supplies", collection_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn fees_paid_until(&self, token_id: TokenId) -> Option<u64> {
+        self.0.query_dictionary("fees_paid_until", token_id)
+    }
+
+    pub fn token_by_physical_id(&self, physical_id: &str) -> Option<TokenId> {
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.update(physical_id.as_bytes());
+        let mut ret = [0u8; 32];
+        hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+        self.0.query_dictionary("physical_ids", hex::encode(ret))
+    }
+
     pub fn balance_of<T: Into<Key>>(&self, account: T) -> U256 {
         self.0
             .query_dictionary("balances", key_to_str(&account.into()))
@@ -293,6 +1695,10 @@ impl CaskInstance {
         self.0.query_named_key(String::from("total_supply"))
     }
 
+    pub fn max_supply(&self) -> Option<U256> {
+        self.0.query_named_key(String::from("max_supply"))
+    }
+
     pub fn kyc_hash(&self) -> Key {
         self.0.query_named_key(String::from("kyc_package_hash"))
     }
@@ -338,3 +1744,25 @@ pub fn key_and_value_to_str<T: CLTyped + ToBytes>(key: &Key, value: &T) -> Strin
     hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
     hex::encode(ret)
 }
+
+/// Mirrors the contract's id derivation: the blake2b hash of the serialized values of `keys`.
+pub fn derive_token_id(meta: &Meta, keys: &[&str]) -> TokenId {
+    let values: Vec<String> = keys
+        .iter()
+        .map(|key| meta.get(*key).expect("Missing token id key").clone())
+        .collect();
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(values.to_bytes().unwrap());
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+    hex::encode(ret)
+}
+
+/// Hex encoded blake2b-256 hash of a document, as expected by `add_token_document`.
+pub fn document_hash(document: &[u8]) -> String {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(document);
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+    hex::encode(ret)
+}
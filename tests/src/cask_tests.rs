@@ -1,9 +1,13 @@
-use casper_types::{account::AccountHash, Key, U256};
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, Key, U256, U512};
 use test_env::TestEnv;
 
-use crate::cask_instance::{CaskInstance, CivicInstance, Commission, Meta, TokenId};
+use crate::cask_instance::{
+    derive_token_id, document_hash, Attestation, CaskInstance, CivicInstance, Commission,
+    InsuranceRecord, Meta, OwnershipRecord, Regauge, TokenDocument, TokenId, Valuation,
+};
 
 const NAME: &str = "CaskNFT";
+const YEAR: u64 = 31_557_600_000;
 const SYMBOL: &str = "CNFT";
 
 mod meta {
@@ -25,6 +29,27 @@ mod meta {
         meta.insert("size".to_string(), "medium".to_string());
         meta
     }
+
+    pub fn identified_cask(cask_number: &str) -> Meta {
+        let mut meta = Meta::new();
+        meta.insert("distillery_code".to_string(), "GLN".to_string());
+        meta.insert("cask_number".to_string(), cask_number.to_string());
+        meta.insert("fill_year".to_string(), "2015".to_string());
+        meta
+    }
+
+    pub fn bottle() -> Meta {
+        let mut meta = Meta::new();
+        meta.insert("size".to_string(), "70cl".to_string());
+        meta
+    }
+
+    pub fn kyc(country: &str) -> Meta {
+        let mut meta = Meta::new();
+        meta.insert("status".to_string(), "active".to_string());
+        meta.insert("country".to_string(), country.to_string());
+        meta
+    }
 }
 
 mod commission {
@@ -65,6 +90,19 @@ fn deploy() -> (TestEnv, CivicInstance, CaskInstance, AccountHash) {
     (env, kyc_token, cask_token, owner)
 }
 
+/// Mints the plain `123456` cask without a commission, the fixture most tests start from.
+fn mint_one(token: &CaskInstance, owner: AccountHash, recipient: AccountHash) -> TokenId {
+    let token_id = TokenId::from("123456");
+    token.mint(
+        owner,
+        recipient,
+        Some(vec![token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    token_id
+}
+
 #[test]
 fn test_deploy() {
     let (_, _, token, owner) = deploy();
@@ -665,3 +703,2477 @@ fn test_token_commission_update_from_owner() {
         "12".to_string(),
     );
 }
+
+#[test]
+fn test_balance_owed_defaults_to_zero() {
+    let (env, _, token, _) = deploy();
+    let ali = env.next_user();
+
+    assert_eq!(token.balance_owed(ali), U512::zero());
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_withdraw_more_than_owed() {
+    let (env, _, token, _) = deploy();
+    let ali = env.next_user();
+
+    token.withdraw(ali, U512::from(1), None);
+}
+
+#[test]
+fn test_settle_sale_and_withdraw() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let artist = env.next_user();
+    let broker = env.next_user();
+    let token_id = TokenId::from("123456");
+    let token_commission = commission::commission(
+        vec!["artist".to_string(), "broker".to_string()],
+        vec![Key::Account(artist), Key::Account(broker)],
+        vec!["10".to_string(), "5".to_string()],
+    );
+
+    token.mint(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![token_commission],
+    );
+    token.approve(ali, bob, vec![token_id.clone()]);
+    token.settle_sale(
+        bob,
+        token_id.clone(),
+        ali,
+        bob,
+        U512::from(1_000_000),
+        env.main_purse(bob),
+    );
+    assert_eq!(token.owner_of(token_id), Some(Key::Account(bob)));
+    assert_eq!(token.balance_owed(artist), U512::from(100_000));
+    assert_eq!(token.balance_owed(broker), U512::from(50_000));
+    assert_eq!(token.balance_owed(ali), U512::from(850_000));
+    assert_eq!(
+        env.purse_balance(token.contract_purse()),
+        U512::from(1_000_000)
+    );
+
+    token.withdraw(ali, U512::from(850_000), None);
+    assert_eq!(token.balance_owed(ali), U512::zero());
+    token.withdraw(artist, U512::from(40_000), Some(env.main_purse(artist)));
+    assert_eq!(token.balance_owed(artist), U512::from(60_000));
+    assert_eq!(
+        env.purse_balance(token.contract_purse()),
+        U512::from(110_000)
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_settle_sale_of_unapproved_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.settle_sale(
+        bob,
+        token_id,
+        ali,
+        bob,
+        U512::from(1_000),
+        env.main_purse(bob),
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_settle_sale_for_non_owner_seller() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.settle_sale(
+        bob,
+        token_id,
+        bob,
+        bob,
+        U512::from(1_000),
+        env.main_purse(bob),
+    );
+}
+
+#[test]
+fn test_default_commission_applies_without_override() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let default_commission = commission::commission(
+        vec!["distillery".to_string()],
+        vec![bob.into()],
+        vec!["5".to_string()],
+    );
+
+    token.set_default_commission(owner, default_commission.clone());
+    let token_id = mint_one(&token, owner, ali);
+
+    assert_eq!(token.default_commission(), default_commission);
+    assert_eq!(token.token_commission(token_id.clone()), None);
+    assert_eq!(
+        token.effective_commission(token_id),
+        Some((default_commission, "default".to_string()))
+    );
+}
+
+#[test]
+fn test_clear_token_commission() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let token_commission = commission::commission(
+        vec!["artist".to_string()],
+        vec![ali.into()],
+        vec!["10".to_string()],
+    );
+
+    token.mint_copies(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        meta::big_cask(),
+        token_commission.clone(),
+        1,
+    );
+    assert_eq!(
+        token.effective_commission(token_id.clone()),
+        Some((token_commission, "token".to_string()))
+    );
+
+    token.clear_token_commission(owner, token_id.clone());
+    assert_eq!(token.token_commission(token_id.clone()), None);
+    assert_eq!(
+        token.effective_commission(token_id),
+        Some((Commission::new(), "default".to_string()))
+    );
+}
+
+#[test]
+fn test_delete_last_commission_property_restores_default() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+    let default_commission = commission::commission(
+        vec!["distillery".to_string()],
+        vec![bob.into()],
+        vec!["5".to_string()],
+    );
+
+    token.set_default_commission(owner, default_commission.clone());
+    token.mint_copies(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        meta::big_cask(),
+        commission::commission(
+            vec!["artist".to_string()],
+            vec![ali.into()],
+            vec!["10".to_string()],
+        ),
+        1,
+    );
+    token.update_token_commission(
+        owner,
+        token_id.clone(),
+        "artist".to_string(),
+        ali,
+        "DELETE".to_string(),
+        String::new(),
+    );
+
+    assert_eq!(token.token_commission(token_id.clone()), None);
+    assert_eq!(
+        token.effective_commission(token_id),
+        Some((default_commission, "default".to_string()))
+    );
+}
+
+#[test]
+fn test_effective_commission_of_missing_token() {
+    let (_, _, token, _) = deploy();
+
+    assert_eq!(token.effective_commission(TokenId::from("123456")), None);
+}
+
+#[test]
+fn test_token_metas_and_commissions() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+    let token_ids = vec![token_id, TokenId::from("missing")];
+
+    assert_eq!(
+        token.token_metas(token_ids.clone()),
+        vec![Some(meta::big_cask()), None]
+    );
+    assert_eq!(
+        token.token_commissions(token_ids),
+        vec![Some((Commission::new(), "default".to_string())), None]
+    );
+
+    let token_ids: Vec<TokenId> = (0..101).map(|i| i.to_string()).collect();
+    assert_eq!(token.token_metas(token_ids.clone()).len(), 100);
+    assert_eq!(token.token_commissions(token_ids).len(), 100);
+}
+
+#[test]
+fn test_token_info() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id.clone()], 5000);
+    let info = token.token_info(token_id.clone()).unwrap();
+    assert_eq!(info.owner, Key::Account(bob));
+    assert_eq!(info.meta, meta::big_cask());
+    assert_eq!(info.commission, Commission::new());
+    assert_eq!(info.commission_source, "default");
+    assert_eq!(info.status, "active");
+    assert_eq!(
+        info.minted_at,
+        token.ownership_history(token_id.clone())[0].timestamp
+    );
+    assert_eq!(info.last_transferred_at, 5000);
+    assert!(!info.locked);
+
+    token.lock_as_collateral(bob, token_id.clone(), lender);
+    assert!(token.token_info(token_id.clone()).unwrap().locked);
+    assert_eq!(token.token_info(TokenId::from("missing")), None);
+}
+
+#[test]
+fn test_token_info_of_reminted_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.burn(owner, ali, vec![token_id.clone()]);
+    token.mint_at(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+        5000,
+    );
+    let info = token.token_info(token_id).unwrap();
+    assert_eq!(info.minted_at, 5000);
+    assert_eq!(info.last_transferred_at, 5000);
+}
+
+#[test]
+#[should_panic = "User(20)"]
+fn test_set_default_commission_from_non_admin() {
+    let (env, _, token, _) = deploy();
+    let ali = env.next_user();
+
+    token.set_default_commission(ali, Commission::new());
+}
+
+#[test]
+fn test_transfer_to_eligible_jurisdiction() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_token_jurisdictions(
+        owner,
+        token_id.clone(),
+        vec!["GB".to_string(), "FR".to_string()],
+    );
+    assert_eq!(
+        token.token_jurisdictions(token_id.clone()),
+        Some(vec!["GB".to_string(), "FR".to_string()])
+    );
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id.clone()]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_to_ineligible_jurisdiction() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_token_jurisdictions(owner, token_id.clone(), vec!["GB".to_string()]);
+
+    kyc.mint(owner, bob, None, meta::kyc("US"));
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_from_to_ineligible_jurisdiction() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_token_jurisdictions(owner, token_id.clone(), vec!["GB".to_string()]);
+
+    kyc.mint(owner, bob, None, meta::kyc("US"));
+    token.transfer_from(owner, ali, bob, vec![token_id]);
+}
+
+#[test]
+fn test_denylist_from_compliance() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_compliance(owner, bob);
+    assert!(token.is_compliance(bob));
+
+    token.add_to_denylist(bob, ali);
+    assert!(token.is_denylisted(ali));
+
+    token.remove_from_denylist(bob, ali);
+    assert!(!token.is_denylisted(ali));
+}
+
+#[test]
+#[should_panic = "User(20)"]
+fn test_denylist_from_non_compliance() {
+    let (env, _, token, _) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.add_to_denylist(bob, ali);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_from_denylisted_account() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_mint_to_denylisted_account() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.mint_copies(owner, ali, None, meta::big_cask(), Commission::new(), 1);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_grant_role_to_denylisted_account() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.grant_minter(owner, ali);
+}
+
+#[test]
+fn test_force_transfer_from_denylisted_account() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let recovery = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.force_transfer(owner, ali, recovery, vec![token_id.clone()], "sanctioned");
+
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(recovery));
+}
+
+#[test]
+fn test_execute_recovery_after_delay() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_recovery_delay(owner, 1000);
+    token.request_recovery(owner, ali, bob, vec![token_id.clone()], 5000);
+    token.execute_recovery(owner, 0, 6000);
+
+    assert_eq!(token.owner_of(token_id.clone()).unwrap(), Key::Account(bob));
+    assert_eq!(
+        token.ownership_history(token_id).pop(),
+        Some(OwnershipRecord {
+            from: Some(Key::Account(ali)),
+            to: Some(Key::Account(bob)),
+            timestamp: 6000,
+            reason: "recovery".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_ownership_history_is_bounded() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    for _ in 0..10 {
+        token.transfer(ali, bob, vec![token_id.clone()]);
+        token.transfer(bob, ali, vec![token_id.clone()]);
+    }
+
+    let history = token.ownership_history(token_id);
+    assert_eq!(history.len(), 20);
+    assert_eq!(history[0].from, Some(Key::Account(ali)));
+    assert_eq!(history[0].reason, "transfer");
+    assert_eq!(history[19].to, Some(Key::Account(ali)));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_execute_recovery_before_delay() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_recovery_delay(owner, 1000);
+    token.request_recovery(owner, ali, bob, vec![token_id], 5000);
+    token.execute_recovery(owner, 0, 5999);
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_execute_cancelled_recovery() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_recovery_delay(owner, 1000);
+    token.request_recovery(owner, ali, bob, vec![token_id], 5000);
+    token.cancel_recovery(ali, 0);
+    token.execute_recovery(owner, 0, 6000);
+}
+
+fn deploy_with_quorum() -> (TestEnv, CaskInstance, AccountHash, AccountHash, TokenId) {
+    deploy_guarding(&["transfer_from"])
+}
+
+/// Deploys with a second admin and a quorum of two over `entry_points`.
+fn deploy_guarding(
+    entry_points: &[&str],
+) -> (TestEnv, CaskInstance, AccountHash, AccountHash, TokenId) {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_admin(owner, bob);
+    for entry_point in entry_points {
+        token.set_guarded_entry_point(owner, entry_point, true);
+    }
+    token.set_quorum_threshold(owner, 2);
+    (env, token, owner, bob, token_id)
+}
+
+fn transfer_from_args(sender: Key, recipient: Key, token_ids: Vec<TokenId>) -> Vec<u8> {
+    let mut args = sender.to_bytes().unwrap();
+    args.append(&mut recipient.to_bytes().unwrap());
+    args.append(&mut token_ids.to_bytes().unwrap());
+    args
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_transfer_from_without_quorum() {
+    let (env, token, owner, _, token_id) = deploy_with_quorum();
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+
+    token.transfer_from(owner, ali, Key::Account(carol), vec![token_id]);
+}
+
+#[test]
+fn test_guarded_transfer_from_with_quorum() {
+    let (env, token, owner, bob, token_id) = deploy_with_quorum();
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+    let args = transfer_from_args(ali, Key::Account(carol), vec![token_id.clone()]);
+
+    token.propose(owner, "transfer_from", args, 10_000, 1_000);
+    assert_eq!(token.owner_of(token_id.clone()).unwrap(), ali);
+
+    token.approve_proposal(bob, 0, 2_000);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(carol));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_approve_expired_proposal() {
+    let (env, token, owner, bob, token_id) = deploy_with_quorum();
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+    let args = transfer_from_args(ali, Key::Account(carol), vec![token_id]);
+
+    token.propose(owner, "transfer_from", args, 10_000, 1_000);
+    token.approve_proposal(bob, 0, 10_000);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_force_transfer_without_quorum() {
+    let (env, token, owner, _, token_id) = deploy_guarding(&["force_transfer"]);
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.force_transfer(
+        owner,
+        ali,
+        Key::Account(carol),
+        vec![token_id],
+        "COURT_ORDER",
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_request_recovery_without_quorum() {
+    let (env, token, owner, _, token_id) = deploy_guarding(&["request_recovery"]);
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+
+    token.request_recovery(owner, ali, Key::Account(carol), vec![token_id], 1_000);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_execute_recovery_without_quorum() {
+    let (env, token, owner, _, token_id) = deploy_guarding(&["execute_recovery"]);
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+
+    token.set_recovery_delay(owner, 1000);
+    token.request_recovery(owner, ali, Key::Account(carol), vec![token_id], 1_000);
+    token.execute_recovery(owner, 0, 2_000);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_merge_tokens_without_quorum() {
+    let (_, token, owner, _, token_id) = deploy_guarding(&["merge_tokens"]);
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let second_token_id = TokenId::from("654321");
+
+    token.mint(
+        owner,
+        ali,
+        Some(vec![second_token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    token.merge_tokens(
+        owner,
+        vec![token_id, second_token_id],
+        meta::big_cask(),
+        "UNION",
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_set_default_commission_without_quorum() {
+    let (_, token, owner, bob, _) = deploy_guarding(&["set_default_commission"]);
+    let default_commission = commission::commission(
+        vec!["platform".to_string()],
+        vec![bob.into()],
+        vec!["5".to_string()],
+    );
+
+    token.set_default_commission(owner, default_commission);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_clear_token_commission_without_quorum() {
+    let (_, token, owner, _, token_id) = deploy_guarding(&["clear_token_commission"]);
+
+    token.clear_token_commission(owner, token_id);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_revoke_admin_without_quorum() {
+    let (_, token, owner, bob, _) = deploy_guarding(&["revoke_admin"]);
+
+    token.revoke_admin(owner, bob);
+}
+
+#[test]
+fn test_guarded_revoke_admin_with_quorum() {
+    let (env, token, owner, bob, _) = deploy_guarding(&["revoke_admin"]);
+    let carol = env.next_user();
+    token.grant_admin(owner, carol);
+
+    token.propose(
+        owner,
+        "revoke_admin",
+        Key::Account(carol).to_bytes().unwrap(),
+        10_000,
+        1_000,
+    );
+    assert!(token.is_admin(carol));
+
+    token.approve_proposal(bob, 0, 2_000);
+    assert!(!token.is_admin(carol));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_grant_minter_without_quorum() {
+    let (env, token, owner, _, _) = deploy_guarding(&["grant_minter"]);
+    let carol = env.next_user();
+
+    token.grant_minter(owner, carol);
+}
+
+#[test]
+fn test_guarded_grant_minter_with_quorum() {
+    let (env, token, owner, bob, _) = deploy_guarding(&["grant_minter"]);
+    let carol = env.next_user();
+
+    token.propose(
+        owner,
+        "grant_minter",
+        Key::Account(carol).to_bytes().unwrap(),
+        10_000,
+        1_000,
+    );
+    assert!(!token.is_minter(carol));
+
+    token.approve_proposal(bob, 0, 2_000);
+    assert!(token.is_minter(carol));
+}
+
+#[test]
+fn test_guarded_force_transfer_with_quorum() {
+    let (env, token, owner, bob, token_id) = deploy_guarding(&["force_transfer"]);
+    let ali = token.owner_of(token_id.clone()).unwrap();
+    let carol = env.next_user();
+    let mut args = transfer_from_args(ali, Key::Account(carol), vec![token_id.clone()]);
+    args.append(&mut "COURT_ORDER".to_string().to_bytes().unwrap());
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.propose(owner, "force_transfer", args, 10_000, 1_000);
+    assert_eq!(token.owner_of(token_id.clone()).unwrap(), ali);
+
+    token.approve_proposal(bob, 0, 2_000);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(carol));
+}
+
+#[test]
+fn test_owner_burn() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let token_commission = commission::commission(
+        vec!["artist".to_string()],
+        vec![owner.into()],
+        vec!["10".to_string()],
+    );
+
+    token.mint_copies(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        meta::big_cask(),
+        token_commission,
+        1,
+    );
+    token.owner_burn(
+        ali,
+        ali,
+        vec![token_id.clone()],
+        Some("SETTLED".to_string()),
+    );
+
+    assert_eq!(token.total_supply(), U256::zero());
+    assert_eq!(token.owner_of(token_id.clone()), None);
+    assert_eq!(token.token_commission(token_id), None);
+}
+
+#[test]
+fn test_owner_burn_from_approved() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.approve(ali, bob, vec![token_id.clone()]);
+    token.owner_burn(bob, ali, vec![token_id.clone()], None);
+
+    assert_eq!(token.owner_of(token_id), None);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_owner_burn_from_non_owner() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.owner_burn(bob, ali, vec![token_id], None);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_owner_burn_by_denylisted_owner() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.owner_burn(ali, ali, vec![token_id], None);
+}
+
+#[test]
+fn test_global_token_index() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_ids = vec![TokenId::from("1"), TokenId::from("2"), TokenId::from("3")];
+
+    token.mint_copies(
+        owner,
+        ali,
+        Some(token_ids[..2].to_vec()),
+        meta::big_cask(),
+        Commission::new(),
+        2,
+    );
+    token.mint_copies(
+        owner,
+        bob,
+        Some(token_ids[2..].to_vec()),
+        meta::big_cask(),
+        Commission::new(),
+        1,
+    );
+    assert_eq!(
+        token.token_by_index(U256::from(0)),
+        Some(token_ids[0].clone())
+    );
+    assert_eq!(
+        token.token_by_index(U256::from(1)),
+        Some(token_ids[1].clone())
+    );
+    assert_eq!(
+        token.token_by_index(U256::from(2)),
+        Some(token_ids[2].clone())
+    );
+
+    token.burn(owner, ali, vec![token_ids[0].clone()]);
+    assert_eq!(
+        token.token_by_index(U256::from(0)),
+        Some(token_ids[2].clone())
+    );
+    assert_eq!(
+        token.token_by_index(U256::from(1)),
+        Some(token_ids[1].clone())
+    );
+    assert_eq!(token.token_by_index(U256::from(2)), None);
+}
+
+#[test]
+fn test_paginate_tokens_across_burns() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_ids: Vec<TokenId> = (1..=4).map(|i| TokenId::from(i.to_string())).collect();
+
+    token.mint_copies(
+        owner,
+        ali,
+        Some(token_ids.clone()),
+        meta::big_cask(),
+        Commission::new(),
+        4,
+    );
+    token.burn(owner, ali, vec![token_ids[0].clone()]);
+
+    let mut owned = token.tokens_of(ali, U256::zero(), 2);
+    assert_eq!(owned.len(), 2);
+    owned.append(&mut token.tokens_of(ali, U256::from(2), 2));
+    owned.sort();
+    assert_eq!(owned, token_ids[1..].to_vec());
+    assert!(token.tokens_of(ali, U256::from(3), 2).is_empty());
+
+    assert_eq!(
+        token.all_tokens(U256::zero(), 2),
+        vec![token_ids[3].clone(), token_ids[1].clone()]
+    );
+    assert_eq!(
+        token.all_tokens(U256::from(2), 2),
+        vec![token_ids[2].clone()]
+    );
+    assert!(token.all_tokens(U256::from(3), 2).is_empty());
+}
+
+#[test]
+fn test_paginate_tokens_from_max_offset() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    mint_one(&token, owner, ali);
+
+    assert!(token.tokens_of(ali, U256::MAX, 2).is_empty());
+    assert!(token.all_tokens(U256::MAX, 2).is_empty());
+}
+
+const TOKEN_ID_KEYS: [&str; 3] = ["distillery_code", "cask_number", "fill_year"];
+
+fn set_token_id_keys(token: &CaskInstance, owner: AccountHash) {
+    token.set_token_id_keys(
+        owner,
+        TOKEN_ID_KEYS.iter().map(|key| key.to_string()).collect(),
+    );
+}
+
+#[test]
+fn test_mint_with_derived_token_ids() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    set_token_id_keys(&token, owner);
+    token.mint(
+        owner,
+        ali,
+        None,
+        vec![meta::identified_cask("1"), meta::identified_cask("2")],
+        vec![Commission::new(), Commission::new()],
+    );
+
+    let first_token_id = derive_token_id(&meta::identified_cask("1"), &TOKEN_ID_KEYS);
+    let second_token_id = derive_token_id(&meta::identified_cask("2"), &TOKEN_ID_KEYS);
+    assert_eq!(token.owner_of(first_token_id).unwrap(), Key::Account(ali));
+    assert_eq!(token.owner_of(second_token_id).unwrap(), Key::Account(ali));
+}
+
+#[test]
+#[should_panic = "User(3) [65539]"]
+fn test_mint_duplicate_derived_token_id() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    set_token_id_keys(&token, owner);
+    token.mint(
+        owner,
+        ali,
+        None,
+        vec![meta::identified_cask("1")],
+        vec![Commission::new()],
+    );
+    token.mint(
+        owner,
+        ali,
+        None,
+        vec![meta::identified_cask("1")],
+        vec![Commission::new()],
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_mint_without_token_id_keys() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    set_token_id_keys(&token, owner);
+    token.mint(
+        owner,
+        ali,
+        None,
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+}
+
+#[test]
+fn test_token_by_physical_id() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("custom_token_id");
+
+    token.set_unique_meta_key(owner, "cask_number");
+    token.mint(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        vec![meta::identified_cask("1")],
+        vec![Commission::new()],
+    );
+    assert_eq!(token.token_by_physical_id("1"), Some(token_id.clone()));
+
+    token.update_token_meta(
+        owner,
+        token_id.clone(),
+        "cask_number".to_string(),
+        "2".to_string(),
+    );
+    assert_eq!(token.token_by_physical_id("1"), None);
+    assert_eq!(token.token_by_physical_id("2"), Some(token_id.clone()));
+
+    token.burn(owner, ali, vec![token_id]);
+    assert_eq!(token.token_by_physical_id("2"), None);
+}
+
+#[test]
+#[should_panic = "User(3) [65539]"]
+fn test_mint_duplicate_physical_id() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_unique_meta_key(owner, "cask_number");
+    token.mint(
+        owner,
+        ali,
+        Some(vec![TokenId::from("first")]),
+        vec![meta::identified_cask("1")],
+        vec![Commission::new()],
+    );
+    token.mint(
+        owner,
+        ali,
+        Some(vec![TokenId::from("second")]),
+        vec![meta::identified_cask("1")],
+        vec![Commission::new()],
+    );
+}
+
+#[test]
+#[should_panic = "User(3) [65539]"]
+fn test_update_token_meta_to_duplicate_physical_id() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_ids = vec![TokenId::from("first"), TokenId::from("second")];
+
+    token.set_unique_meta_key(owner, "cask_number");
+    token.mint(
+        owner,
+        ali,
+        Some(token_ids.clone()),
+        vec![meta::identified_cask("1"), meta::identified_cask("2")],
+        vec![Commission::new(), Commission::new()],
+    );
+    token.update_token_meta(
+        owner,
+        token_ids[1].clone(),
+        "cask_number".to_string(),
+        "1".to_string(),
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_set_unique_meta_key_after_mint() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint(
+        owner,
+        ali,
+        None,
+        vec![meta::identified_cask("1")],
+        vec![Commission::new()],
+    );
+    token.set_unique_meta_key(owner, "cask_number");
+}
+
+fn attestation(custodian: AccountHash, inspection_date: u64, posted_at: u64) -> Attestation {
+    Attestation {
+        warehouse_id: "WH-1".to_string(),
+        inspection_date,
+        volume: 25_000,
+        abv: 6_350,
+        document_hash: "a1".repeat(32),
+        custodian: Key::Account(custodian),
+        posted_at,
+    }
+}
+
+#[test]
+fn test_post_attestation() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 1000, 2000),
+        2000,
+    );
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 3000, 3000),
+        3000,
+    );
+
+    assert_eq!(
+        token.token_attestations(token_id),
+        vec![
+            attestation(custodian, 1000, 2000),
+            attestation(custodian, 3000, 3000)
+        ]
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_post_attestation_older_than_latest() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 3000, 3000),
+        3000,
+    );
+    token.post_attestation(
+        custodian,
+        token_id,
+        &attestation(custodian, 1000, 4000),
+        4000,
+    );
+}
+
+#[test]
+fn test_attestation_history_is_bounded() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    for day in 1..=21 {
+        token.post_attestation(
+            custodian,
+            token_id.clone(),
+            &attestation(custodian, day * 1000, day * 1000),
+            day * 1000,
+        );
+    }
+
+    let attestations = token.token_attestations(token_id);
+    assert_eq!(attestations.len(), 20);
+    assert_eq!(attestations[0], attestation(custodian, 2000, 2000));
+    assert_eq!(attestations[19], attestation(custodian, 21_000, 21_000));
+}
+
+#[test]
+fn test_post_attestation_to_reminted_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 5000, 5000),
+        5000,
+    );
+    token.burn(owner, ali, vec![token_id.clone()]);
+    assert!(token.token_attestations(token_id.clone()).is_empty());
+
+    mint_one(&token, owner, ali);
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 1000, 6000),
+        6000,
+    );
+    assert_eq!(
+        token.token_attestations(token_id),
+        vec![attestation(custodian, 1000, 6000)]
+    );
+}
+
+#[test]
+#[should_panic = "User(20)"]
+fn test_post_attestation_from_non_custodian() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.post_attestation(ali, token_id, &attestation(ali, 1000, 1000), 1000);
+}
+
+#[test]
+fn test_transfer_recently_attested_token() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.set_attestation_max_age(owner, 1000);
+    token.set_block_stale_transfers(owner, true);
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 5000, 5000),
+        5000,
+    );
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id.clone()], 5500);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_stale_token() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.set_attestation_max_age(owner, 1000);
+    token.set_block_stale_transfers(owner, true);
+    token.post_attestation(
+        custodian,
+        token_id.clone(),
+        &attestation(custodian, 5000, 5000),
+        5000,
+    );
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id], 7000);
+}
+
+#[test]
+fn test_replace_token_document() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let certificate = document_hash(b"ownership certificate");
+    let reissued_certificate = document_hash(b"reissued ownership certificate");
+    let token_id = mint_one(&token, owner, ali);
+
+    token.add_token_document(
+        owner,
+        token_id.clone(),
+        "certificate",
+        certificate.clone(),
+        "ipfs://certificate",
+    );
+    token.replace_token_document(
+        owner,
+        token_id.clone(),
+        0,
+        reissued_certificate.clone(),
+        "ipfs://reissued",
+    );
+
+    assert_eq!(
+        token.token_documents(token_id),
+        vec![
+            TokenDocument {
+                doc_type: "certificate".to_string(),
+                hash: certificate,
+                uri: "ipfs://certificate".to_string(),
+                added_by: Key::Account(owner),
+                added_at: 0,
+                revoked: true,
+                replaced_by: Some(1),
+            },
+            TokenDocument {
+                doc_type: "certificate".to_string(),
+                hash: reissued_certificate,
+                uri: "ipfs://reissued".to_string(),
+                added_by: Key::Account(owner),
+                added_at: 0,
+                revoked: false,
+                replaced_by: None,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_burn_clears_token_documents() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.add_token_document(
+        owner,
+        token_id.clone(),
+        "certificate",
+        document_hash(b"ownership certificate"),
+        "ipfs://certificate",
+    );
+    token.burn(owner, ali, vec![token_id.clone()]);
+    mint_one(&token, owner, ali);
+
+    assert!(token.token_documents(token_id).is_empty());
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_add_token_document_with_invalid_hash() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.add_token_document(
+        owner,
+        token_id,
+        "certificate",
+        "not a hash".to_string(),
+        "ipfs://certificate",
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_revoke_revoked_token_document() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.add_token_document(
+        owner,
+        token_id.clone(),
+        "bond",
+        document_hash(b"bond"),
+        "ipfs://bond",
+    );
+    token.revoke_token_document(owner, token_id.clone(), 0);
+    token.revoke_token_document(owner, token_id, 0);
+}
+
+#[test]
+fn test_split_into_bottles() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("cask");
+    let token_commission = commission::commission(
+        vec!["broker".to_string()],
+        vec![bob.into()],
+        vec!["10".to_string()],
+    );
+
+    token.mint(
+        owner,
+        ali,
+        Some(vec![token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![token_commission.clone()],
+    );
+    token.split_into_bottles(owner, token_id.clone(), 3, meta::bottle());
+
+    let children = token.children_of(token_id.clone());
+    assert_eq!(children.len(), 3);
+    assert_eq!(token.total_supply(), U256::from(4));
+    for (index, child) in children.into_iter().enumerate() {
+        let mut bottle_meta = meta::bottle();
+        bottle_meta.insert("parent_id".to_string(), token_id.clone());
+        bottle_meta.insert("bottle_number".to_string(), (index + 1).to_string());
+        assert_eq!(token.owner_of(child.clone()).unwrap(), Key::Account(ali));
+        assert_eq!(token.token_meta(child.clone()).unwrap(), bottle_meta);
+        assert_eq!(
+            token.token_commission(child.clone()).unwrap(),
+            token_commission
+        );
+        assert_eq!(token.parent_of(child), Some(token_id.clone()));
+    }
+}
+
+#[test]
+fn test_split_with_token_id_keys_and_unique_meta_key() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let cask_meta = meta::identified_cask("1");
+    let token_id = derive_token_id(&cask_meta, &TOKEN_ID_KEYS);
+
+    set_token_id_keys(&token, owner);
+    token.set_unique_meta_key(owner, "cask_number");
+    token.mint(
+        owner,
+        ali,
+        None,
+        vec![cask_meta.clone()],
+        vec![Commission::new()],
+    );
+    let mut bottle_meta = cask_meta;
+    bottle_meta.extend(meta::bottle());
+    token.split_into_bottles(owner, token_id.clone(), 2, bottle_meta);
+
+    let children = token.children_of(token_id.clone());
+    assert_eq!(children.len(), 2);
+    for child in children {
+        assert_ne!(child, token_id);
+        assert_eq!(token.owner_of(child.clone()).unwrap(), Key::Account(ali));
+        assert_eq!(token.token_meta(child).unwrap().get("cask_number"), None);
+    }
+    assert_eq!(token.token_by_physical_id("1"), Some(token_id));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_bottled_cask() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.split_into_bottles(owner, token_id.clone(), 3, meta::bottle());
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_split_into_too_many_bottles() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.split_into_bottles(owner, token_id, 201, meta::bottle());
+}
+
+#[test]
+fn test_merge_tokens() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cid = env.next_user();
+    let token_ids = vec![TokenId::from("first"), TokenId::from("second")];
+    let first_commission = commission::commission(
+        vec!["broker".to_string()],
+        vec![bob.into()],
+        vec!["10".to_string()],
+    );
+    let second_commission = commission::commission(
+        vec!["artist".to_string()],
+        vec![cid.into()],
+        vec!["5".to_string()],
+    );
+
+    token.mint(
+        owner,
+        ali,
+        Some(token_ids.clone()),
+        vec![meta::big_cask(), meta::big_cask()],
+        vec![first_commission, second_commission],
+    );
+    token.merge_tokens(owner, token_ids.clone(), meta::big_cask(), "UNION");
+
+    assert_eq!(token.owner_of(token_ids[0].clone()), None);
+    assert_eq!(token.owner_of(token_ids[1].clone()), None);
+    assert_eq!(token.balance_of(ali), U256::one());
+    let merged_id = token.get_token_by_index(ali, U256::zero()).unwrap();
+    assert_eq!(token.lineage_of(merged_id.clone()), token_ids);
+    assert_eq!(
+        token.token_commission(merged_id).unwrap(),
+        commission::commission(
+            vec!["artist".to_string(), "broker".to_string()],
+            vec![cid.into(), bob.into()],
+            vec!["5".to_string(), "10".to_string()],
+        )
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_merge_tokens_of_different_owners() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint(
+        owner,
+        ali,
+        Some(vec![TokenId::from("first")]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    token.mint(
+        owner,
+        bob,
+        Some(vec![TokenId::from("second")]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    token.merge_tokens(
+        owner,
+        vec![TokenId::from("first"), TokenId::from("second")],
+        meta::big_cask(),
+        "DEFAULT",
+    );
+}
+
+#[test]
+fn test_transfer_matured_cask() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_fill_timestamp(owner, token_id.clone(), 1000, 1000);
+    token.set_min_transfer_age(owner, 3 * YEAR);
+    assert_eq!(token.fill_timestamp(token_id.clone()), Some(1000));
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id.clone()], 1000 + 3 * YEAR);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_new_make_spirit() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_fill_timestamp(owner, token_id.clone(), 1000, 1000);
+    token.set_min_transfer_age(owner, 3 * YEAR);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id], 1000 + 2 * YEAR);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_cask_without_fill_timestamp() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_min_transfer_age(owner, 3 * YEAR);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id], 1000 + 3 * YEAR);
+}
+
+#[test]
+fn test_admin_corrects_fill_timestamp() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let minter = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_minter(owner, minter);
+    token.set_fill_timestamp(minter, token_id.clone(), 1000, 1000);
+    token.set_fill_timestamp(owner, token_id.clone(), 500, 1000);
+    assert_eq!(token.fill_timestamp(token_id), Some(500));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_minter_resets_fill_timestamp() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let minter = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_minter(owner, minter);
+    token.set_fill_timestamp(minter, token_id.clone(), 1000, 1000);
+    token.set_fill_timestamp(minter, token_id, 500, 1000);
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_set_future_fill_timestamp() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_fill_timestamp(owner, token_id, 2000, 1000);
+}
+
+fn regauge(custodian: AccountHash, date: u64, lpa: u64, correction: bool) -> Regauge {
+    Regauge {
+        date,
+        bulk: 25_000,
+        abv: 6_000,
+        lpa,
+        custodian: Key::Account(custodian),
+        correction,
+    }
+}
+
+#[test]
+fn test_regauge_log() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.add_regauge(
+        custodian,
+        token_id.clone(),
+        &regauge(custodian, 1000, 15_000, false),
+        1000,
+    );
+    token.add_regauge(
+        custodian,
+        token_id.clone(),
+        &regauge(custodian, 2000, 14_700, false),
+        2000,
+    );
+    token.add_regauge(
+        owner,
+        token_id.clone(),
+        &regauge(owner, 3000, 14_900, true),
+        3000,
+    );
+
+    assert_eq!(
+        token.token_regauges(token_id),
+        vec![
+            regauge(custodian, 1000, 15_000, false),
+            regauge(custodian, 2000, 14_700, false),
+            regauge(owner, 3000, 14_900, true)
+        ]
+    );
+}
+
+#[test]
+fn test_regauge_log_is_bounded() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    for day in 1..=21 {
+        token.add_regauge(
+            custodian,
+            token_id.clone(),
+            &regauge(custodian, day * 1000, 15_000 - day * 10, false),
+            day * 1000,
+        );
+    }
+
+    let regauges = token.token_regauges(token_id);
+    assert_eq!(regauges.len(), 20);
+    assert_eq!(regauges[0], regauge(custodian, 2000, 14_980, false));
+    assert_eq!(regauges[19], regauge(custodian, 21_000, 14_790, false));
+}
+
+#[test]
+fn test_regauge_reminted_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.add_regauge(
+        custodian,
+        token_id.clone(),
+        &regauge(custodian, 5000, 14_000, false),
+        5000,
+    );
+    token.burn(owner, ali, vec![token_id.clone()]);
+    mint_one(&token, owner, ali);
+    token.add_regauge(
+        custodian,
+        token_id.clone(),
+        &regauge(custodian, 1000, 15_000, false),
+        6000,
+    );
+
+    assert_eq!(
+        token.token_regauges(token_id),
+        vec![regauge(custodian, 1000, 15_000, false)]
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_regauge_with_increased_lpa() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let custodian = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_custodian(owner, custodian);
+    token.add_regauge(
+        custodian,
+        token_id.clone(),
+        &regauge(custodian, 1000, 15_000, false),
+        1000,
+    );
+    token.add_regauge(
+        custodian,
+        token_id,
+        &regauge(custodian, 2000, 15_100, false),
+        2000,
+    );
+}
+
+fn deploy_with_storage_fees() -> (TestEnv, CivicInstance, CaskInstance, AccountHash) {
+    let (env, kyc, token, owner) = deploy();
+    let treasury = env.next_user();
+    token.set_treasury(owner, treasury);
+    token.set_storage_fee(owner, U512::from(100_000_000_000u64));
+    token.set_storage_fee_grace_period(owner, 1000);
+    token.set_block_unpaid_transfers(owner, true);
+    (env, kyc, token, owner)
+}
+
+#[test]
+fn test_transfer_within_storage_fee_grace_period() {
+    let (env, kyc, token, owner) = deploy_with_storage_fees();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    assert_eq!(token.fees_paid_until(token_id.clone()), Some(0));
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id.clone()], 1000);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_with_overdue_storage_fees() {
+    let (env, kyc, token, owner) = deploy_with_storage_fees();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    let token_id = mint_one(&token, owner, ali);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id], YEAR);
+}
+
+#[test]
+fn test_storage_fees_owed_across_fee_change() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_storage_fee(owner, U512::from(1000));
+    token.set_storage_fee_at(owner, U512::from(3000), YEAR / 2);
+
+    assert_eq!(
+        token.storage_fees_owed_at(token_id.clone(), YEAR / 2),
+        U512::from(500)
+    );
+    assert_eq!(token.storage_fees_owed_at(token_id, YEAR), U512::from(2000));
+}
+
+#[test]
+fn test_split_stops_storage_fees() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_storage_fee(owner, U512::from(1000));
+    token.split_into_bottles_at(owner, token_id.clone(), 2, meta::bottle(), YEAR / 2);
+
+    assert_eq!(
+        token.storage_fees_owed_at(token_id.clone(), YEAR),
+        U512::from(500)
+    );
+    for child in token.children_of(token_id) {
+        assert_eq!(token.fees_paid_until(child.clone()), None);
+        assert_eq!(token.storage_fees_owed_at(child, YEAR), U512::zero());
+    }
+}
+
+#[test]
+fn test_merge_carries_storage_fees() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_ids = vec![TokenId::from("first"), TokenId::from("second")];
+
+    token.mint(
+        owner,
+        ali,
+        Some(token_ids.clone()),
+        vec![meta::big_cask(), meta::big_cask()],
+        vec![Commission::new(), Commission::new()],
+    );
+    token.set_storage_fee(owner, U512::from(1000));
+    token.merge_tokens_at(owner, token_ids, meta::big_cask(), "DEFAULT", YEAR / 2);
+
+    let merged_id = token.get_token_by_index(ali, U256::zero()).unwrap();
+    assert_eq!(token.fees_paid_until(merged_id.clone()), Some(0));
+    assert_eq!(
+        token.storage_fees_owed_at(merged_id, YEAR),
+        U512::from(1500)
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_burn_with_unpaid_storage_fees() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_storage_fee(owner, U512::from(1000));
+    token.burn_at(owner, ali, vec![token_id], YEAR / 2);
+}
+
+fn insurance(expiry: u64) -> InsuranceRecord {
+    InsuranceRecord {
+        policy_hash: document_hash(b"policy-1"),
+        insurer: "Lloyd's".to_string(),
+        coverage_value: U512::from(1_000_000_000_000u64),
+        expiry,
+    }
+}
+
+#[test]
+fn test_insurance_expiring_before() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_ids = vec![
+        TokenId::from("uninsured"),
+        TokenId::from("expiring"),
+        TokenId::from("covered"),
+    ];
+
+    token.mint_copies(
+        owner,
+        ali,
+        Some(token_ids.clone()),
+        meta::big_cask(),
+        Commission::new(),
+        3,
+    );
+    token.grant_compliance(owner, owner);
+    token.set_token_insurance(owner, token_ids[1].clone(), &insurance(1000));
+    token.set_token_insurance(owner, token_ids[2].clone(), &insurance(5000));
+
+    assert_eq!(
+        token.insurance_expiring_before(2000, U256::zero(), 10),
+        vec![token_ids[1].clone()]
+    );
+}
+
+#[test]
+fn test_transfer_insured_token() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.set_token_insurance(owner, token_id.clone(), &insurance(5000));
+    token.set_require_insurance(owner, true);
+    assert_eq!(
+        token.token_insurance(token_id.clone()),
+        Some(insurance(5000))
+    );
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id.clone()], 4000);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_token_with_expired_insurance() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.set_token_insurance(owner, token_id.clone(), &insurance(5000));
+    token.set_require_insurance(owner, true);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_at(ali, bob, vec![token_id], 6000);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_approve_uninsured_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_require_insurance(owner, true);
+    token.approve(ali, bob, vec![token_id]);
+}
+
+#[test]
+fn test_owner_valuation_follows_transfers() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let oracle = env.next_user();
+    let token_ids = vec![TokenId::from("first"), TokenId::from("second")];
+
+    token.mint(
+        owner,
+        ali,
+        Some(token_ids.clone()),
+        vec![meta::big_cask(), meta::big_cask()],
+        vec![Commission::new(), Commission::new()],
+    );
+    token.grant_oracle(owner, oracle);
+    token.publish_valuation(oracle, token_ids[0].clone(), U512::from(100), 1000, 1000);
+    token.publish_valuation(oracle, token_ids[0].clone(), U512::from(150), 2000, 2000);
+    token.publish_valuation(oracle, token_ids[1].clone(), U512::from(70), 2000, 2000);
+    assert_eq!(token.owner_valuation(ali), U512::from(220));
+    assert_eq!(
+        token.token_valuations(token_ids[0].clone()).pop(),
+        Some(Valuation {
+            value: U512::from(150),
+            timestamp: 2000,
+            source: "auction".to_string(),
+            oracle: Key::Account(oracle),
+        })
+    );
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_ids[0].clone()]);
+    assert_eq!(token.owner_valuation(ali), U512::from(70));
+    assert_eq!(token.owner_valuation(bob), U512::from(150));
+
+    token.burn(owner, ali, vec![token_ids[1].clone()]);
+    assert_eq!(token.owner_valuation(ali), U512::zero());
+}
+
+#[test]
+fn test_split_drops_cask_valuation() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let oracle = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+    let other_token_id = TokenId::from("654321");
+
+    token.mint(
+        owner,
+        ali,
+        Some(vec![other_token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    token.grant_oracle(owner, oracle);
+    token.publish_valuation(oracle, token_id.clone(), U512::from(100), 1000, 1000);
+    token.publish_valuation(oracle, other_token_id, U512::from(70), 1000, 1000);
+    assert_eq!(token.owner_valuation(ali), U512::from(170));
+
+    token.split_into_bottles(owner, token_id.clone(), 2, meta::bottle());
+    assert_eq!(token.owner_valuation(ali), U512::from(70));
+
+    token.burn(owner, ali, vec![token_id]);
+    assert_eq!(token.owner_valuation(ali), U512::from(70));
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_publish_valuation_of_bottled_cask() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let oracle = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_oracle(owner, oracle);
+    token.split_into_bottles(owner, token_id.clone(), 2, meta::bottle());
+    token.publish_valuation(oracle, token_id, U512::from(100), 1000, 1000);
+}
+
+#[test]
+#[should_panic = "User(20)"]
+fn test_publish_valuation_from_non_oracle() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.publish_valuation(ali, token_id, U512::from(100), 1000, 1000);
+}
+
+#[test]
+fn test_release_collateral() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+    assert_eq!(
+        token.collateral_lender(token_id.clone()),
+        Some(Key::Account(lender))
+    );
+
+    token.release(lender, token_id.clone());
+    assert_eq!(token.collateral_lender(token_id.clone()), None);
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id.clone()]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+fn test_liquidate_collateral() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+
+    kyc.mint(owner, lender, None, meta::kyc("GB"));
+    token.liquidate(lender, token_id.clone(), lender);
+    assert_eq!(
+        token.owner_of(token_id.clone()).unwrap(),
+        Key::Account(lender)
+    );
+    assert_eq!(token.collateral_lender(token_id.clone()), None);
+    assert_eq!(
+        token.ownership_history(token_id).pop().unwrap().reason,
+        "liquidation"
+    );
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_lock_as_collateral_by_denylisted_owner() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.lock_as_collateral(ali, token_id, lender);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_lock_as_collateral_to_denylisted_lender() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, lender);
+    token.lock_as_collateral(ali, token_id, lender);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_collateralized_token() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_owner_burn_collateralized_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+    token.owner_burn(ali, ali, vec![token_id], None);
+}
+
+#[test]
+fn test_set_user() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cat = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_user(ali, token_id.clone(), bob, 5000);
+    assert_eq!(
+        token.token_user(token_id.clone()),
+        Some((Key::Account(bob), 5000))
+    );
+    assert_eq!(token.owner_of(token_id.clone()).unwrap(), Key::Account(ali));
+
+    token.approve(ali, cat, vec![token_id.clone()]);
+    token.set_user(cat, token_id.clone(), cat, 6000);
+    assert_eq!(token.token_user(token_id), Some((Key::Account(cat), 6000)));
+}
+
+#[test]
+fn test_user_of_expires() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_user(ali, token_id.clone(), bob, 5000);
+    assert_eq!(
+        token.user_of_at(token_id.clone(), 4999),
+        Some(Key::Account(bob))
+    );
+    assert_eq!(token.user_of_at(token_id, 5000), None);
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_set_user_with_past_expiry() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_user_at(ali, token_id, bob, 5000, 6000);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_set_denylisted_user() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, bob);
+    token.set_user(ali, token_id, bob, 5000);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_set_user_by_denylisted_owner() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.set_user(ali, token_id, bob, 5000);
+}
+
+#[test]
+fn test_transfer_clears_user() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cat = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_user(ali, token_id.clone(), cat, 5000);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id.clone()]);
+    assert_eq!(token.token_user(token_id), None);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_set_user_from_non_owner() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.set_user(bob, token_id, bob, 5000);
+}
+
+fn mint_one_soulbound(token: &CaskInstance, owner: AccountHash, recipient: AccountHash) -> TokenId {
+    let token_id = TokenId::from("123456");
+    token.mint_soulbound(
+        owner,
+        recipient,
+        Some(vec![token_id.clone()]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    token_id
+}
+
+#[test]
+fn test_mint_soulbound() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one_soulbound(&token, owner, ali);
+
+    assert!(token.is_soulbound(token_id.clone()));
+    assert_eq!(token.owner_of(token_id.clone()).unwrap(), Key::Account(ali));
+
+    token.burn(owner, ali, vec![token_id.clone()]);
+    assert_eq!(token.owner_of(token_id.clone()), None);
+    assert!(!token.is_soulbound(token_id));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_soulbound_token() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one_soulbound(&token, owner, ali);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_transfer_from_soulbound_token() {
+    let (env, kyc, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = mint_one_soulbound(&token, owner, ali);
+
+    kyc.mint(owner, bob, None, meta::kyc("GB"));
+    token.transfer_from(owner, ali, bob, vec![token_id]);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_force_transfer_soulbound_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let recovery = env.next_user();
+    let token_id = mint_one_soulbound(&token, owner, ali);
+
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.force_transfer(owner, ali, recovery, vec![token_id], "sanctioned");
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_force_transfer_collateralized_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let recovery = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.force_transfer(owner, ali, recovery, vec![token_id], "sanctioned");
+}
+
+#[test]
+fn test_force_transfer_after_clearing_collateral_lock() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let recovery = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+    token.grant_compliance(owner, owner);
+    token.add_to_denylist(owner, ali);
+    token.clear_collateral_lock(owner, token_id.clone());
+    assert_eq!(token.collateral_lender(token_id.clone()), None);
+
+    token.force_transfer(owner, ali, recovery, vec![token_id.clone()], "sanctioned");
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(recovery));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_clear_collateral_lock_of_allowed_owner() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let lender = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+
+    token.lock_as_collateral(ali, token_id.clone(), lender);
+    token.grant_compliance(owner, owner);
+    token.clear_collateral_lock(owner, token_id);
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_split_soulbound_token() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = mint_one_soulbound(&token, owner, ali);
+
+    token.split_into_bottles(owner, token_id, 2, meta::bottle());
+}
+
+#[test]
+fn test_mint_to_collection() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let distillery = env.next_user();
+    let token_ids = vec![TokenId::from("123456"), TokenId::from("654321")];
+    let drop_commission = commission::commission(
+        vec!["distillery".to_string()],
+        vec![Key::Account(distillery)],
+        vec!["5".to_string()],
+    );
+
+    token.create_collection(owner, "drop-1", "First Drop", drop_commission.clone(), None);
+    token.grant_collection_minter(owner, "drop-1", distillery);
+    token.mint_to_collection(
+        distillery,
+        "drop-1",
+        ali,
+        Some(token_ids.clone()),
+        vec![meta::big_cask(), meta::big_cask()],
+        vec![Commission::new(), Commission::new()],
+    );
+    assert_eq!(
+        token.collection_of(token_ids[0].clone()),
+        Some("drop-1".to_string())
+    );
+    assert_eq!(token.collection_supply("drop-1"), U256::from(2));
+    assert_eq!(
+        token.effective_commission(token_ids[0].clone()),
+        Some((drop_commission, "collection".to_string()))
+    );
+
+    token.mint(
+        owner,
+        ali,
+        Some(vec![TokenId::from("777")]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    assert_eq!(
+        token.collection_of(TokenId::from("777")),
+        Some("default".to_string())
+    );
+    assert_eq!(token.collection_supply("default"), U256::one());
+
+    token.burn(owner, ali, vec![token_ids[0].clone()]);
+    assert_eq!(token.collection_of(token_ids[0].clone()), None);
+    assert_eq!(token.collection_supply("drop-1"), U256::one());
+}
+
+#[test]
+fn test_update_default_collection_commission() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let distillery = env.next_user();
+    let token_id = mint_one(&token, owner, ali);
+    let drop_commission = commission::commission(
+        vec!["distillery".to_string()],
+        vec![Key::Account(distillery)],
+        vec!["5".to_string()],
+    );
+    assert_eq!(
+        token.effective_commission(token_id.clone()),
+        Some((Commission::new(), "default".to_string()))
+    );
+
+    token.update_collection(owner, "default", "Renamed", drop_commission.clone());
+    let collection = token.collection("default").unwrap();
+    assert_eq!(collection.name, "Renamed");
+    assert_eq!(collection.commission, drop_commission);
+    assert_eq!(
+        token.effective_commission(token_id),
+        Some((drop_commission, "collection".to_string()))
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_update_unknown_collection() {
+    let (_, _, token, owner) = deploy();
+
+    token.update_collection(owner, "drop-1", "First Drop", Commission::new());
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_guarded_update_collection_without_quorum() {
+    let (_, token, owner, _, _) = deploy_guarding(&["update_collection"]);
+
+    token.update_collection(owner, "default", "Renamed", Commission::new());
+}
+
+#[test]
+#[should_panic = "User(20)"]
+fn test_mint_to_collection_from_other_collection_minter() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    let distillery = env.next_user();
+
+    token.create_collection(owner, "drop-1", "First Drop", Commission::new(), None);
+    token.create_collection(owner, "drop-2", "Second Drop", Commission::new(), None);
+    token.grant_collection_minter(owner, "drop-1", distillery);
+    token.mint_to_collection(
+        distillery,
+        "drop-2",
+        ali,
+        Some(vec![TokenId::from("123456")]),
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_mint_to_collection_over_cap() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.create_collection(
+        owner,
+        "drop-1",
+        "First Drop",
+        Commission::new(),
+        Some(U256::one()),
+    );
+    token.mint_to_collection(
+        owner,
+        "drop-1",
+        ali,
+        Some(vec![TokenId::from("123456"), TokenId::from("654321")]),
+        vec![meta::big_cask(), meta::big_cask()],
+        vec![Commission::new(), Commission::new()],
+    );
+}
+
+#[test]
+fn test_lower_supply_cap() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_supply_cap(owner, None, U256::from(3));
+    token.mint_copies(owner, ali, None, meta::big_cask(), Commission::new(), 1);
+    token.set_supply_cap(owner, None, U256::one());
+    assert_eq!(token.max_supply(), Some(U256::one()));
+
+    token.create_collection(owner, "drop-1", "First Drop", Commission::new(), None);
+    token.set_supply_cap(owner, Some("drop-1"), U256::from(10));
+    token.set_supply_cap(owner, Some("drop-1"), U256::from(5));
+    assert_eq!(token.collection("drop-1").unwrap().cap, Some(U256::from(5)));
+}
+
+#[test]
+fn test_remaining_supply() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+    token.create_collection(owner, "drop-1", "First Drop", Commission::new(), None);
+    assert_eq!(token.remaining_supply(None), None);
+    assert_eq!(token.remaining_supply(Some("drop-1")), None);
+
+    token.set_supply_cap(owner, Some("drop-1"), U256::from(3));
+    token.mint_to_collection(
+        owner,
+        "drop-1",
+        ali,
+        None,
+        vec![meta::big_cask()],
+        vec![Commission::new()],
+    );
+    assert_eq!(token.remaining_supply(None), None);
+    assert_eq!(token.remaining_supply(Some("drop-1")), Some(U256::from(2)));
+
+    token.set_supply_cap(owner, None, U256::from(10));
+    assert_eq!(token.remaining_supply(None), Some(U256::from(9)));
+    assert_eq!(token.remaining_supply(Some("drop-1")), Some(U256::from(2)));
+
+    token.mint_copies(owner, ali, None, meta::big_cask(), Commission::new(), 8);
+    assert_eq!(token.remaining_supply(None), Some(U256::one()));
+    assert_eq!(token.remaining_supply(Some("drop-1")), Some(U256::one()));
+}
+
+#[test]
+#[should_panic = "User(2) [65538]"]
+fn test_mint_over_supply_cap() {
+    let (env, _, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_supply_cap(owner, None, U256::one());
+    token.mint_copies(owner, ali, None, meta::big_cask(), Commission::new(), 2);
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_raise_supply_cap_without_quorum() {
+    let (_, _, token, owner) = deploy();
+
+    token.set_supply_cap(owner, None, U256::from(2));
+    token.set_supply_cap(owner, None, U256::from(3));
+}
+
+#[test]
+fn test_raise_supply_cap_with_quorum() {
+    let (_, token, owner, bob, _) = deploy_with_quorum();
+    token.set_supply_cap(owner, None, U256::one());
+
+    let mut args = Option::<String>::None.to_bytes().unwrap();
+    args.append(&mut U256::from(5).to_bytes().unwrap());
+    token.propose(owner, "raise_supply_cap", args, 10_000, 1_000);
+    assert_eq!(token.max_supply(), Some(U256::one()));
+
+    token.approve_proposal(bob, 0, 2_000);
+    assert_eq!(token.max_supply(), Some(U256::from(5)));
+}
+
+#[test]
+#[should_panic = "User(1) [65537]"]
+fn test_propose_raise_supply_cap_without_quorum() {
+    let (_, _, token, owner) = deploy();
+    token.set_supply_cap(owner, None, U256::one());
+
+    let mut args = Option::<String>::None.to_bytes().unwrap();
+    args.append(&mut U256::from(5).to_bytes().unwrap());
+    token.propose(owner, "raise_supply_cap", args, 10_000, 1_000);
+}
@@ -0,0 +1,45 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLType, CLTyped, ContractHash, Key, RuntimeArgs,
+};
+
+/// Named key of the caller's account the returned bytes are stored under.
+const RESULT_KEY: &str = "call_result";
+
+/// An entry point's return value, kept serialized so the tests can decode any type.
+struct RawValue(Vec<u8>);
+
+impl CLTyped for RawValue {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for RawValue {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Ok((RawValue(bytes.to_vec()), &[]))
+    }
+}
+
+#[no_mangle]
+fn call() {
+    let contract_hash = runtime::get_named_arg::<Key>("contract_hash")
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+    let entry_point = runtime::get_named_arg::<String>("entry_point");
+    let args = runtime::get_named_arg::<Vec<u8>>("args");
+    let args: RuntimeArgs = bytesrepr::deserialize(args).unwrap_or_revert();
+    let RawValue(ret) = runtime::call_contract(contract_hash, &entry_point, args);
+    runtime::put_key(RESULT_KEY, storage::new_uref(ret).into());
+}
@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, CLTyped, ContractHash, RuntimeArgs,
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    runtime_args, CLTyped, ContractHash, Key, RuntimeArgs,
 };
 
 use crate::{utils::DeploySource, TestEnv};
@@ -64,4 +66,60 @@ impl TestContract {
         };
         self.env.run(sender, session_code, session_args);
     }
+
+    /// Calls a getter through the `call-session` wasm, deployed by the contract owner, and
+    /// decodes what it returned.
+    pub fn call_getter<T: FromBytes>(&self, entry_point: &str, session_args: RuntimeArgs) -> T {
+        self.env.run(
+            self.contract_owner,
+            DeploySource::Code(PathBuf::from("call-session.wasm")),
+            self.call_session_args(entry_point, session_args),
+        );
+        self.call_result()
+    }
+
+    pub fn call_getter_at<T: FromBytes>(
+        &self,
+        entry_point: &str,
+        session_args: RuntimeArgs,
+        block_time: u64,
+    ) -> T {
+        self.env.run_at(
+            self.contract_owner,
+            DeploySource::Code(PathBuf::from("call-session.wasm")),
+            self.call_session_args(entry_point, session_args),
+            block_time,
+        );
+        self.call_result()
+    }
+
+    fn call_session_args(&self, entry_point: &str, session_args: RuntimeArgs) -> RuntimeArgs {
+        runtime_args! {
+            "contract_hash" => Key::Hash(self.contract_hash()),
+            "entry_point" => entry_point.to_string(),
+            "args" => session_args.to_bytes().unwrap()
+        }
+    }
+
+    fn call_result<T: FromBytes>(&self) -> T {
+        let bytes: Vec<u8> = self
+            .env
+            .query_account_named_key(self.contract_owner, &[String::from("call_result")]);
+        bytesrepr::deserialize(bytes).expect("Wrong type in call result.")
+    }
+
+    pub fn call_contract_at(
+        &self,
+        sender: AccountHash,
+        entry_point: &str,
+        session_args: RuntimeArgs,
+        block_time: u64,
+    ) {
+        let session_code = DeploySource::ByContractHash {
+            hash: ContractHash::new(self.contract_hash()),
+            method: entry_point.to_string(),
+        };
+        self.env
+            .run_at(sender, session_code, session_args, block_time);
+    }
 }
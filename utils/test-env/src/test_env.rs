@@ -3,6 +3,7 @@ use std::sync::{Arc, Mutex};
 use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST};
 use casper_types::{
     account::AccountHash, bytesrepr::FromBytes, CLTyped, Key, PublicKey, RuntimeArgs, SecretKey,
+    URef, U512,
 };
 
 use crate::utils::{deploy, fund_account, query, query_dictionary_item, DeploySource};
@@ -30,6 +31,23 @@ impl TestEnv {
         )
     }
 
+    pub fn run_at(
+        &self,
+        sender: AccountHash,
+        session_code: DeploySource,
+        session_args: RuntimeArgs,
+        block_time: u64,
+    ) {
+        deploy(
+            &mut self.state.lock().unwrap().builder,
+            &sender,
+            &session_code,
+            session_args,
+            true,
+            Some(block_time),
+        )
+    }
+
     pub fn next_user(&self) -> AccountHash {
         self.state.lock().unwrap().next_user()
     }
@@ -56,6 +74,20 @@ impl TestEnv {
             .unwrap()
             .query_account_named_key(account, path)
     }
+
+    pub fn main_purse(&self, account: AccountHash) -> URef {
+        self.state
+            .lock()
+            .unwrap()
+            .builder
+            .get_account(account)
+            .expect("should be an account.")
+            .main_purse()
+    }
+
+    pub fn purse_balance(&self, purse: URef) -> U512 {
+        self.state.lock().unwrap().builder.get_purse_balance(purse)
+    }
 }
 
 impl Default for TestEnv {